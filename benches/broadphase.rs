@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use sidescrolling_adventure_game::components::{Collider, LevelGeometry, Position};
+use sidescrolling_adventure_game::plugins::broadphase::SpatialHash;
+use sidescrolling_adventure_game::plugins::physics::swept_aabb_collision;
+
+const TILE_SIZE: f32 = 32.0;
+const BODY_COUNT: usize = 64;
+const COLUMNS: usize = 250;
+
+/// A solid block of tiles, COLUMNS wide, with its top at y = 400
+fn tile_level(world: &mut World, tile_count: usize) -> Vec<(Entity, LevelGeometry)> {
+    (0..tile_count)
+        .map(|i| {
+            let x = (i % COLUMNS) as f32 * TILE_SIZE;
+            let y = 400.0 + (i / COLUMNS) as f32 * TILE_SIZE;
+            let geometry = LevelGeometry::new(x, y, TILE_SIZE, TILE_SIZE);
+            (world.spawn(geometry.clone()).id(), geometry)
+        })
+        .collect()
+}
+
+/// Bodies falling onto the top row, spread across its width
+fn bodies() -> Vec<Position> {
+    let width = COLUMNS as f32 * TILE_SIZE;
+    (0..BODY_COUNT)
+        .map(|i| Position::new(width * i as f32 / BODY_COUNT as f32, 360.0))
+        .collect()
+}
+
+fn earliest_time<'a>(
+    position: &Position,
+    collider: &Collider,
+    geometry: impl IntoIterator<Item = &'a LevelGeometry>,
+    movement: Vec2,
+) -> Option<f32> {
+    geometry
+        .into_iter()
+        .filter_map(|geo| swept_aabb_collision(position, collider, geo, movement))
+        .map(|(time, _)| time)
+        .reduce(f32::min)
+}
+
+fn bench_broadphase(c: &mut Criterion) {
+    let collider = Collider::new(32.0, 32.0);
+    let movement = Vec2::new(3.0, 12.0);
+    let mut group = c.benchmark_group("ground_sweep");
+
+    for tile_count in [1_000, 5_000, 20_000] {
+        let mut world = World::new();
+        let level = tile_level(&mut world, tile_count);
+        let mut spatial_hash = SpatialHash::default();
+        for (entity, geometry) in &level {
+            spatial_hash.insert(*entity, geometry);
+        }
+        let lookup: std::collections::HashMap<Entity, &LevelGeometry> =
+            level.iter().map(|(entity, geo)| (*entity, geo)).collect();
+        let positions = bodies();
+
+        group.bench_with_input(
+            BenchmarkId::new("all_geometry", tile_count),
+            &positions,
+            |b, positions| {
+                b.iter(|| {
+                    for position in positions {
+                        black_box(earliest_time(
+                            position,
+                            &collider,
+                            level.iter().map(|(_, geo)| geo),
+                            movement,
+                        ));
+                    }
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("spatial_hash", tile_count),
+            &positions,
+            |b, positions| {
+                b.iter(|| {
+                    for position in positions {
+                        let nearby =
+                            spatial_hash.query_around(position, &collider, movement.length());
+                        black_box(earliest_time(
+                            position,
+                            &collider,
+                            nearby.iter().map(|entity| lookup[entity]),
+                            movement,
+                        ));
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_broadphase);
+criterion_main!(benches);
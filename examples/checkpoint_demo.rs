@@ -1,6 +1,7 @@
 use bevy::prelude::*;
-use sidescrolling_adventure_game::components::{AbilitySet, Player, Position};
+use sidescrolling_adventure_game::components::{AbilitySet, Collider, Player, Position};
 use sidescrolling_adventure_game::enums::Ability;
+use sidescrolling_adventure_game::plugins::PhysicsPlugin;
 use sidescrolling_adventure_game::plugins::checkpoint::{
     Checkpoint, CheckpointPlugin, LoadFromDisk, RestoreCheckpoint, SaveToDisk,
 };
@@ -8,7 +9,7 @@ use sidescrolling_adventure_game::plugins::checkpoint::{
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(CheckpointPlugin)
+        .add_plugins((PhysicsPlugin, CheckpointPlugin))
         .add_systems(Startup, setup)
         .add_systems(Update, demo_checkpoint_system)
         .run();
@@ -19,12 +20,13 @@ fn setup(mut commands: Commands) {
     commands.spawn((
         Player,
         Position::new(100.0, 200.0),
+        Collider::new(32.0, 64.0),
         AbilitySet::from(vec![Ability::HighJump]),
     ));
 
     // Spawn checkpoint
-    commands.spawn((
-        Checkpoint::new("cp_demo".to_string()),
+    commands.spawn(Checkpoint::bundle(
+        "cp_demo".to_string(),
         Position::new(150.0, 200.0),
     ));
 
@@ -1,4 +1,4 @@
-use crate::enums::{Ability, AnimationType};
+use crate::enums::{Ability, AnimationType, BoxCorner, VolumeEffect};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -53,6 +53,53 @@ impl Collider {
             offset_y: 0.0,
         }
     }
+
+    /// Collider centered on the entity's position rather than hanging from its top-left
+    pub fn centered(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            offset_x: -width / 2.0,
+            offset_y: -height / 2.0,
+        }
+    }
+}
+
+/// Collision layers - which layers an entity is on and which it collides with
+/// Two entities collide only when each one's mask includes a layer of the other.
+/// Bodies without this component use the default layers.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollisionLayers {
+    pub layer: u32, // Bitset of layers this belongs to
+    pub mask: u32,  // Bitset of layers this collides with
+}
+
+impl CollisionLayers {
+    pub const NONE: u32 = 0;
+    pub const DEFAULT: u32 = 1 << 0; // Level geometry and bodies without a layer
+    pub const PLAYER: u32 = 1 << 1;
+    pub const ENEMY: u32 = 1 << 2;
+    pub const ALL: u32 = u32::MAX;
+
+    pub fn new(layer: u32, mask: u32) -> Self {
+        Self { layer, mask }
+    }
+
+    /// Layers for the player's body
+    pub fn player() -> Self {
+        Self::new(Self::PLAYER, Self::ALL)
+    }
+
+    /// Whether two entities on these layers collide
+    pub fn interacts_with(&self, other: &CollisionLayers) -> bool {
+        self.layer & other.mask != 0 && other.layer & self.mask != 0
+    }
+}
+
+impl Default for CollisionLayers {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT, Self::ALL)
+    }
 }
 
 /// Grounded state - tracks ground contact
@@ -60,6 +107,7 @@ impl Collider {
 pub struct GroundedState {
     pub is_grounded: bool,
     pub ground_normal: Vec2,
+    pub ground_material: SurfaceMaterial, // Material of the surface standing on
 }
 
 impl Default for GroundedState {
@@ -67,10 +115,52 @@ impl Default for GroundedState {
         Self {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ground_material: SurfaceMaterial::default(),
         }
     }
 }
 
+/// Surface material - how level geometry feels to bodies touching it
+/// Geometry without this component uses the default material
+#[derive(Component, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurfaceMaterial {
+    pub friction: f32, // Scales how quickly bodies speed up and slow down on it (1 = normal)
+    pub restitution: f32, // Fraction of impact speed bounced back
+    pub conveyor_velocity: f32, // Horizontal speed bodies resting on it are carried at
+}
+
+impl SurfaceMaterial {
+    pub fn new(friction: f32, restitution: f32, conveyor_velocity: f32) -> Self {
+        Self {
+            friction,
+            restitution,
+            conveyor_velocity,
+        }
+    }
+
+    /// Slippery floor that bodies take a while to speed up or stop on
+    pub fn ice() -> Self {
+        Self::new(0.05, 0.0, 0.0)
+    }
+
+    /// Surface that bounces back most of a landing
+    pub fn trampoline() -> Self {
+        Self::new(1.0, 0.9, 0.0)
+    }
+
+    /// Belt that carries bodies standing on it at `speed` (negative = left)
+    pub fn conveyor(speed: f32) -> Self {
+        Self::new(1.0, 0.0, speed)
+    }
+}
+
+impl Default for SurfaceMaterial {
+    fn default() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+}
+
 /// Ability set - tracks unlocked abilities
 #[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AbilitySet {
@@ -108,11 +198,68 @@ impl From<Vec<Ability>> for AbilitySet {
 }
 
 /// Swing state - active swing data
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[derive(Component, Clone, Debug, PartialEq)]
 pub struct SwingState {
     pub anchor_point: Vec2,
-    pub rope_length: f32,
-    pub angular_velocity: f32,
+    pub rope_length: f32,      // Whole rope, from the anchor to the player
+    pub angular_velocity: f32, // Around the current pivot
+    pub wraps: Vec<RopeWrap>,  // Corners the rope is bent around, anchor side first
+    pub min_length: f32,       // Shortest rope_length can be reeled in to
+    pub max_length: f32,       // Longest rope_length can be let out to
+}
+
+impl SwingState {
+    pub fn new(anchor_point: Vec2, rope_length: f32, angular_velocity: f32) -> Self {
+        Self {
+            anchor_point,
+            rope_length,
+            angular_velocity,
+            wraps: Vec::new(),
+            min_length: rope_length,
+            max_length: rope_length,
+        }
+    }
+
+    /// Let the rope be reeled between `min_length` and `max_length`, never yanking it
+    /// away from its current length
+    pub fn with_length_limits(mut self, min_length: f32, max_length: f32) -> Self {
+        self.min_length = min_length.min(self.rope_length);
+        self.max_length = max_length.max(self.rope_length);
+        self
+    }
+
+    /// Point the free end of the rope swings around: the last corner wrapped, or the anchor
+    pub fn pivot(&self) -> Vec2 {
+        self.wraps
+            .last()
+            .map_or(self.anchor_point, |wrap| wrap.corner)
+    }
+
+    /// Rope left between the pivot and the player
+    pub fn free_length(&self) -> f32 {
+        let mut from = self.anchor_point;
+        let mut wrapped = 0.0;
+        for wrap in &self.wraps {
+            wrapped += from.distance(wrap.corner);
+            from = wrap.corner;
+        }
+        self.rope_length - wrapped
+    }
+}
+
+/// A geometry corner the rope is bent around
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RopeWrap {
+    pub corner: Vec2,
+    pub side: f32, // Side of the rope the player was on when it wrapped; crossing back unwraps
+}
+
+/// Swing point a player would attach to, refreshed every fixed tick
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SwingTarget {
+    pub point: Option<Entity>, // Nearest swing point in range, if any
+    pub anchor: Vec2,          // Its position
+    pub distance: f32,         // From the player's center
 }
 
 /// Wall climb state - active climb data
@@ -197,17 +344,99 @@ impl VisualEffect {
 pub struct PlayerIntent {
     pub move_left: bool,
     pub move_right: bool,
+    pub move_x: f32, // Analog horizontal input from -1 (left) to 1 (right), 0 if none
+    pub move_up: bool, // Reels the rope in while swinging
+    pub move_down: bool,
     pub jump_pressed: bool,
+    pub jump_just_pressed: bool, // Pressed since the previous fixed tick
     pub jump_just_released: bool,
+    pub swing_pressed: bool, // Held; grabs a swing point in range while airborne
+    pub swing_released: bool, // Released since the previous fixed tick; lets go of the rope
+}
+
+impl PlayerIntent {
+    /// Horizontal input from -1 (full left) to 1 (full right)
+    /// Uses the analog move_x when set, otherwise the held directions at full strength
+    pub fn horizontal(&self) -> f32 {
+        if self.move_x != 0.0 {
+            return self.move_x.clamp(-1.0, 1.0);
+        }
+        match (self.move_left, self.move_right) {
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Fixed ticks left in which the player can still jump after walking off a ledge
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct CoyoteTime {
+    pub ticks_left: u32,
+}
+
+/// Fixed ticks left in which a jump pressed before landing still fires
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct JumpBuffer {
+    pub ticks_left: u32,
 }
 
 /// Level geometry component - static collision data
-#[derive(Component, Clone, Debug, PartialEq)]
+/// x, y, width and height are the bounding box; shape refines it for slopes
+#[derive(Component, Clone, Debug, PartialEq, Default)]
 pub struct LevelGeometry {
     pub x: f32,
     pub y: f32,
     pub width: f32,
     pub height: f32,
+    pub shape: GeometryShape,
+    pub one_way: bool, // Only blocks bodies landing on it from above
+    pub layers: CollisionLayers,
+}
+
+impl LevelGeometry {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            shape: GeometryShape::Box,
+            one_way: false,
+            layers: CollisionLayers::default(),
+        }
+    }
+}
+
+/// Collision shape of level geometry within its bounding box
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GeometryShape {
+    #[default]
+    Box,
+    Triangle(BoxCorner), // Right triangle with the right angle at this corner
+    Ramp(BoxCorner),     // Line segment along the hypotenuse of the same triangle
+}
+
+/// Force volume component - a rectangle that changes physics for bodies overlapping it
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ForceVolume {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub effect: VolumeEffect,
+}
+
+impl ForceVolume {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, effect: VolumeEffect) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            effect,
+        }
+    }
 }
 
 /// Power-up component - represents a collectible ability power-up
@@ -219,7 +448,9 @@ pub struct PowerUp {
 /// Swing point component - represents a point the player can swing from
 #[derive(Component, Clone, Copy, Debug, PartialEq)]
 pub struct SwingPoint {
-    pub range: f32, // Maximum distance player can be to attach
+    pub range: f32,      // Maximum distance player can be to attach
+    pub min_length: f32, // Shortest the rope can be reeled in to
+    pub max_length: f32, // Longest the rope can be let out to
 }
 
 #[cfg(test)]
@@ -273,4 +504,17 @@ mod tests {
         assert_eq!(collider.offset_x, 0.0);
         assert_eq!(collider.offset_y, 0.0);
     }
+
+    #[test]
+    fn test_collision_layers_interaction() {
+        let default = CollisionLayers::default();
+        let player = CollisionLayers::player();
+        let player_only = CollisionLayers::new(CollisionLayers::DEFAULT, CollisionLayers::PLAYER);
+        let ghost = CollisionLayers::new(CollisionLayers::DEFAULT, CollisionLayers::NONE);
+
+        assert!(default.interacts_with(&player));
+        assert!(player.interacts_with(&player_only));
+        assert!(!default.interacts_with(&player_only));
+        assert!(!player.interacts_with(&ghost));
+    }
 }
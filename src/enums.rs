@@ -27,3 +27,59 @@ pub enum AnimationType {
     WallCling,
     Swinging,
 }
+
+/// Corner of an axis-aligned box - locates the right angle of sloped geometry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BoxCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// How a moving platform continues once it reaches the end of its path
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PathMode {
+    Linear,   // Stop at the last waypoint
+    PingPong, // Reverse back along the path
+    Loop,     // Return to the first waypoint and start again
+}
+
+/// What a force volume does to bodies inside it
+/// Accelerations are in pixels per second squared (positive y = down)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum VolumeEffect {
+    Wind {
+        x: f32,
+        y: f32,
+    },
+    Water {
+        buoyancy: f32,   // Upward acceleration
+        drag: f32,       // Fraction of velocity lost per second
+        swim_speed: f32, // Fastest a body moves in the water, and how fast the player swims
+    },
+    Gravity {
+        x: f32, // Replaces normal gravity with this multiple of it, e.g. (0, 0.3) or (0, -1)
+        y: f32,
+    },
+}
+
+/// Game action that input devices are bound to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveLeft,
+    MoveRight,
+    Up,
+    Down,
+    Jump,
+    Swing,
+    Dash,
+    Pause,
+}
+
+/// Which way an analog stick axis must be pushed to count
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AxisDirection {
+    Positive, // Right or up
+    Negative, // Left or down
+}
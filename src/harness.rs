@@ -0,0 +1,400 @@
+use crate::components::{AbilitySet, PlayerIntent, Position};
+use crate::enums::Ability;
+use crate::level::{GeometryData, LevelData, SpawnPoint};
+use crate::plugins::checkpoint::SaveFilePath;
+use crate::plugins::level::{CurrentLevel, spawn_level_entities};
+use crate::plugins::player::{ScriptedIntent, player_bundle};
+use crate::plugins::replay::{
+    InputRecorder, InputRecording, ReplayError, ReplayFailed, StartRecording, StartReplay,
+};
+use crate::plugins::{
+    AbilityPlugin, AnimationPlugin, CheckpointPlugin, LevelPlugin, PhysicsPlugin, PlayerPlugin,
+    RenderTransformPlugin, ReplayPlugin, SwingPlugin,
+};
+use bevy::ecs::system::CommandQueue;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counter keeping each harness's save file apart from others running in parallel
+static NEXT_SAVE_FILE: AtomicUsize = AtomicUsize::new(0);
+
+/// Headless app running every gameplay plugin on a fixed clock, for deterministic tests
+/// Each step feeds one PlayerIntent and runs exactly one fixed tick, so the same script
+/// always produces the same world
+/// Its save file lives in the temp dir and is deleted when the harness is dropped
+pub struct SimulationHarness {
+    pub app: App,
+    player: Entity,
+    ticks: u64,
+    last_intent: PlayerIntent,
+    save_file: PathBuf,
+}
+
+impl SimulationHarness {
+    /// Load a level and spawn the player at its spawn point without abilities
+    pub fn new(level: LevelData) -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins((
+            PlayerPlugin,
+            PhysicsPlugin,
+            AbilityPlugin,
+            SwingPlugin,
+            LevelPlugin,
+            CheckpointPlugin,
+            AnimationPlugin,
+            RenderTransformPlugin,
+            ReplayPlugin,
+        ));
+
+        // Every frame advances the clock by exactly one fixed tick
+        let timestep = app.world.resource::<Time<Fixed>>().timestep();
+        let save_file = std::env::temp_dir().join(format!(
+            "harness_save_{}_{}.json",
+            std::process::id(),
+            NEXT_SAVE_FILE.fetch_add(1, Ordering::Relaxed)
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep))
+            .init_resource::<ScriptedIntent>()
+            .insert_resource(SaveFilePath {
+                path: save_file.clone(),
+            });
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &app.world);
+        spawn_level_entities(&mut commands, &level);
+        queue.apply(&mut app.world);
+
+        let spawn = Position::new(level.spawn_point.x, level.spawn_point.y);
+        let player = app
+            .world
+            .spawn(player_bundle(spawn, AbilitySet::new()))
+            .id();
+        app.insert_resource(CurrentLevel {
+            level_id: level.id.clone(),
+            level_data: level,
+        });
+
+        // The clock only starts on the first frame, which runs startup and no fixed tick
+        app.update();
+
+        Self {
+            app,
+            player,
+            ticks: 0,
+            last_intent: PlayerIntent::default(),
+            save_file,
+        }
+    }
+
+    /// Load a level and play a recording made in it to the end
+    /// The replay plugin resets the level and player, so this is the same as the game starting
+    /// a replay; it fails when the recording's level cannot be loaded
+    pub fn from_recording(
+        level: LevelData,
+        recording: &InputRecording,
+    ) -> Result<Self, ReplayError> {
+        let mut harness = Self::new(level);
+        harness
+            .app
+            .insert_resource(StartReplay::Recording(recording.clone()));
+
+        // The replay starts and plays its first tick in the same frame
+        harness.step(PlayerIntent::default());
+        if let Some(ReplayFailed(error)) = harness
+            .app
+            .world
+            .resource_mut::<Events<ReplayFailed>>()
+            .drain()
+            .next()
+        {
+            return Err(error);
+        }
+        harness.step_n(recording.len().saturating_sub(1), PlayerIntent::default());
+        Ok(harness)
+    }
+
+    /// Record every tick from the next step on, starting from the player's current abilities
+    pub fn start_recording(&mut self) -> &mut Self {
+        self.app.insert_resource(StartRecording);
+        self
+    }
+
+    /// Everything recorded since `start_recording`
+    pub fn recording(&self) -> Option<InputRecording> {
+        self.app
+            .world
+            .get_resource::<InputRecorder>()
+            .map(|recorder| recorder.0.clone())
+    }
+
+    /// The player entity
+    pub fn player(&self) -> Entity {
+        self.player
+    }
+
+    /// Number of fixed ticks stepped so far
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Unlock an ability for the player
+    pub fn grant(&mut self, ability: Ability) -> &mut Self {
+        if let Some(mut abilities) = self.app.world.get_mut::<AbilitySet>(self.player) {
+            abilities.add(ability);
+        }
+        self
+    }
+
+    /// Run one frame with exactly one fixed tick, holding `intent`
+    /// Pressing and letting go of jump are detected from the previous step, as on a keyboard
+    pub fn step(&mut self, mut intent: PlayerIntent) -> &mut Self {
+        intent.jump_just_pressed |= intent.jump_pressed && !self.last_intent.jump_pressed;
+        intent.jump_just_released |= self.last_intent.jump_pressed && !intent.jump_pressed;
+        intent.swing_released |= self.last_intent.swing_pressed && !intent.swing_pressed;
+        self.last_intent = intent;
+        self.app.world.resource_mut::<ScriptedIntent>().0 = intent;
+        self.app.update();
+        self.ticks += 1;
+        self
+    }
+
+    /// Hold `intent` for `ticks` fixed ticks
+    pub fn step_n(&mut self, ticks: usize, intent: PlayerIntent) -> &mut Self {
+        for _ in 0..ticks {
+            self.step(intent);
+        }
+        self
+    }
+
+    /// Step once per scripted intent, in order
+    pub fn run_script(&mut self, script: &[PlayerIntent]) -> &mut Self {
+        for intent in script {
+            self.step(*intent);
+        }
+        self
+    }
+
+    pub fn world(&self) -> &World {
+        &self.app.world
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.app.world
+    }
+
+    /// A component of any entity
+    pub fn get<T: Component>(&self, entity: Entity) -> Option<&T> {
+        self.app.world.get::<T>(entity)
+    }
+
+    /// A component of the player
+    pub fn player_get<T: Component>(&self) -> Option<&T> {
+        self.get::<T>(self.player)
+    }
+
+    pub fn player_position(&self) -> Position {
+        *self
+            .player_get::<Position>()
+            .expect("harness player has a Position")
+    }
+
+    /// Entities with component `T`
+    pub fn entities_with<T: Component>(&mut self) -> Vec<Entity> {
+        self.app
+            .world
+            .query_filtered::<Entity, With<T>>()
+            .iter(&self.app.world)
+            .collect()
+    }
+}
+
+impl Drop for SimulationHarness {
+    fn drop(&mut self) {
+        // Most harnesses never save, so there is usually nothing to delete
+        let _ = fs::remove_file(&self.save_file);
+    }
+}
+
+/// Level made only of solid blocks, each (x, y, width, height), for harness scenarios
+pub fn block_level(id: &str, spawn: Position, blocks: &[(f32, f32, f32, f32)]) -> LevelData {
+    LevelData {
+        id: id.to_string(),
+        width: blocks
+            .iter()
+            .map(|block| block.0 + block.2)
+            .fold(0.0, f32::max),
+        height: blocks
+            .iter()
+            .map(|block| block.1 + block.3)
+            .fold(0.0, f32::max),
+        spawn_point: SpawnPoint {
+            x: spawn.x,
+            y: spawn.y,
+        },
+        geometry: blocks
+            .iter()
+            .map(|&(x, y, width, height)| GeometryData {
+                geometry_type: "platform".to_string(),
+                x,
+                y,
+                width,
+                height,
+                ..Default::default()
+            })
+            .collect(),
+        swing_points: vec![],
+        checkpoints: vec![],
+        power_ups: vec![],
+        transitions: vec![],
+        ability_gates: vec![],
+        moving_platforms: vec![],
+        force_volumes: vec![],
+        pushables: vec![],
+        pressure_plates: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{AnimationState, GroundedState, PowerUp};
+    use crate::enums::AnimationType;
+    use crate::level::PowerUpData;
+
+    const TILE: f32 = 32.0;
+
+    /// Floor ending at x = 0, then a 3-tile pit, then a ledge 3 tiles higher than the floor
+    fn gap_level() -> LevelData {
+        block_level(
+            "gap",
+            Position::new(-200.0, 336.0),
+            &[
+                (-400.0, 400.0, 400.0, 64.0),
+                (3.0 * TILE, 400.0 - 3.0 * TILE, 400.0, 3.0 * TILE + 64.0),
+                (-400.0, 760.0, 1200.0, 40.0), // Bottom of the pit
+            ],
+        )
+    }
+
+    fn run_right() -> PlayerIntent {
+        PlayerIntent {
+            move_right: true,
+            ..Default::default()
+        }
+    }
+
+    fn run_and_jump() -> PlayerIntent {
+        PlayerIntent {
+            move_right: true,
+            jump_pressed: true,
+            ..Default::default()
+        }
+    }
+
+    /// Run up to the edge, take a full jump, and keep running
+    fn jump_the_gap(harness: &mut SimulationHarness) {
+        harness
+            .step_n(5, PlayerIntent::default())
+            .step_n(50, run_right())
+            .step_n(40, run_and_jump())
+            .step_n(60, run_right());
+    }
+
+    #[test]
+    fn test_player_lands_on_spawn_floor() {
+        let mut harness = SimulationHarness::new(gap_level());
+        harness.step_n(10, PlayerIntent::default());
+
+        assert_eq!(harness.player_position(), Position::new(-200.0, 336.0));
+        assert!(harness.player_get::<GroundedState>().unwrap().is_grounded);
+        assert_eq!(harness.ticks(), 10);
+    }
+
+    #[test]
+    fn test_jump_over_three_tile_gap_succeeds_with_high_jump() {
+        let mut harness = SimulationHarness::new(gap_level());
+        harness.grant(Ability::HighJump);
+        jump_the_gap(&mut harness);
+
+        let position = harness.player_position();
+        assert!(position.x > 3.0 * TILE, "Player stopped at {position:?}");
+        assert!(
+            (position.y - (400.0 - 3.0 * TILE - 64.0)).abs() < 0.01,
+            "Player should stand on the ledge, at {position:?}"
+        );
+        assert!(harness.player_get::<GroundedState>().unwrap().is_grounded);
+    }
+
+    #[test]
+    fn test_jump_over_three_tile_gap_fails_without_high_jump() {
+        let mut harness = SimulationHarness::new(gap_level());
+        jump_the_gap(&mut harness);
+
+        let position = harness.player_position();
+        assert!(
+            position.y > 400.0,
+            "Player should have fallen into the pit, at {position:?}"
+        );
+    }
+
+    #[test]
+    fn test_same_script_gives_same_world() {
+        let script: Vec<PlayerIntent> = (0..120)
+            .map(|tick| {
+                if tick % 30 < 20 {
+                    run_and_jump()
+                } else {
+                    run_right()
+                }
+            })
+            .collect();
+
+        let mut first = SimulationHarness::new(gap_level());
+        let mut second = SimulationHarness::new(gap_level());
+        first.grant(Ability::HighJump).run_script(&script);
+        second.grant(Ability::HighJump).run_script(&script);
+
+        assert_eq!(first.player_position(), second.player_position());
+    }
+
+    #[test]
+    fn test_collecting_power_up_unlocks_high_jump() {
+        let mut level = gap_level();
+        level.power_ups.push(PowerUpData {
+            ability_type: Ability::HighJump,
+            x: -100.0,
+            y: 368.0,
+        });
+        let mut harness = SimulationHarness::new(level);
+        assert_eq!(harness.entities_with::<PowerUp>().len(), 1);
+
+        harness.step_n(5, PlayerIntent::default());
+        harness.step_n(30, run_right());
+
+        assert!(harness.entities_with::<PowerUp>().is_empty());
+        assert!(
+            harness
+                .player_get::<AbilitySet>()
+                .unwrap()
+                .has(Ability::HighJump)
+        );
+        assert_eq!(
+            harness.player_get::<AnimationState>().unwrap().current,
+            AnimationType::Running
+        );
+    }
+
+    #[test]
+    fn test_dropping_harness_deletes_save_file() {
+        let harness = SimulationHarness::new(gap_level());
+        let path = harness.world().resource::<SaveFilePath>().path.clone();
+        fs::write(&path, "{}").unwrap();
+
+        drop(harness);
+
+        assert!(!path.exists());
+    }
+}
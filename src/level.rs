@@ -1,4 +1,5 @@
-use crate::enums::Ability;
+use crate::components::{CollisionLayers, SurfaceMaterial};
+use crate::enums::{Ability, BoxCorner, PathMode, VolumeEffect};
 use bevy::prelude::Resource;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,14 @@ pub struct LevelData {
     pub transitions: Vec<TransitionData>,
     #[serde(default)]
     pub ability_gates: Vec<AbilityGateData>,
+    #[serde(default)]
+    pub moving_platforms: Vec<MovingPlatformData>,
+    #[serde(default)]
+    pub force_volumes: Vec<ForceVolumeData>,
+    #[serde(default)]
+    pub pushables: Vec<PushableData>,
+    #[serde(default)]
+    pub pressure_plates: Vec<PressurePlateData>,
 }
 
 /// Spawn point data
@@ -30,7 +39,11 @@ pub struct SpawnPoint {
 }
 
 /// Geometry data for level collision
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// "triangle" and "ramp" types use `corner` to place the right angle of the slope
+/// "oneway" platforms can be jumped through from below
+/// `layers` restricts what collides with it, e.g. a mask of only the player layer
+/// `material` sets its friction, bounciness and conveyor speed
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct GeometryData {
     #[serde(rename = "type")]
     pub geometry_type: String,
@@ -38,6 +51,12 @@ pub struct GeometryData {
     pub y: f32,
     pub width: f32,
     pub height: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corner: Option<BoxCorner>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layers: Option<CollisionLayers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<SurfaceMaterial>,
 }
 
 /// Swing point data
@@ -45,6 +64,10 @@ pub struct GeometryData {
 pub struct SwingPointData {
     pub x: f32,
     pub y: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<f32>, // Shortest the rope can be reeled in to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<f32>, // Longest the rope can be let out to
 }
 
 /// Checkpoint data
@@ -88,6 +111,59 @@ pub struct AbilityGateData {
     pub gate_area: TriggerArea,
 }
 
+/// Moving platform data - starts at the first waypoint and follows the rest
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MovingPlatformData {
+    pub width: f32,
+    pub height: f32,
+    pub waypoints: Vec<WaypointData>,
+    pub mode: PathMode,
+    pub speed: f32, // Pixels per second
+    #[serde(default)]
+    pub pause: f32, // Seconds to wait at each waypoint
+    #[serde(default)]
+    pub one_way: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layers: Option<CollisionLayers>,
+}
+
+/// Force volume data - a rectangle that changes physics while bodies are inside it
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForceVolumeData {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub effect: VolumeEffect,
+}
+
+/// Pushable body data - a crate or boulder with its top-left corner at x, y
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PushableData {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub mass: f32, // Relative to the player
+}
+
+/// Pressure plate data - the area pressed by the player or a pushable
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PressurePlateData {
+    pub id: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Waypoint data - top-left corner of a moving platform
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaypointData {
+    pub x: f32,
+    pub y: f32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,8 +181,14 @@ mod tests {
                 y: 0.0,
                 width: 1920.0,
                 height: 64.0,
+                ..Default::default()
+            }],
+            swing_points: vec![SwingPointData {
+                x: 500.0,
+                y: 800.0,
+                min_length: None,
+                max_length: None,
             }],
-            swing_points: vec![SwingPointData { x: 500.0, y: 800.0 }],
             checkpoints: vec![CheckpointData {
                 id: "cp_01".to_string(),
                 x: 300.0,
@@ -136,6 +218,44 @@ mod tests {
                     height: 200.0,
                 },
             }],
+            moving_platforms: vec![MovingPlatformData {
+                width: 96.0,
+                height: 16.0,
+                waypoints: vec![
+                    WaypointData { x: 600.0, y: 300.0 },
+                    WaypointData { x: 900.0, y: 300.0 },
+                ],
+                mode: PathMode::PingPong,
+                speed: 120.0,
+                pause: 0.5,
+                one_way: true,
+                layers: None,
+            }],
+            force_volumes: vec![ForceVolumeData {
+                x: 0.0,
+                y: 400.0,
+                width: 300.0,
+                height: 200.0,
+                effect: VolumeEffect::Water {
+                    buoyancy: 1100.0,
+                    drag: 2.0,
+                    swim_speed: 150.0,
+                },
+            }],
+            pushables: vec![PushableData {
+                x: 200.0,
+                y: 300.0,
+                width: 48.0,
+                height: 48.0,
+                mass: 2.0,
+            }],
+            pressure_plates: vec![PressurePlateData {
+                id: "gate_switch".to_string(),
+                x: 400.0,
+                y: 340.0,
+                width: 48.0,
+                height: 8.0,
+            }],
         };
 
         // Serialize to JSON
@@ -161,6 +281,7 @@ mod tests {
                     y: 0.0,
                     width: 1920.0,
                     height: 64.0,
+                    ..Default::default()
                 },
                 GeometryData {
                     geometry_type: "wall".to_string(),
@@ -168,6 +289,7 @@ mod tests {
                     y: 64.0,
                     width: 32.0,
                     height: 200.0,
+                    ..Default::default()
                 },
             ],
             swing_points: vec![],
@@ -175,6 +297,10 @@ mod tests {
             power_ups: vec![],
             transitions: vec![],
             ability_gates: vec![],
+            moving_platforms: vec![],
+            force_volumes: vec![],
+            pushables: vec![],
+            pressure_plates: vec![],
         };
 
         // Round-trip through JSON
@@ -211,6 +337,10 @@ mod tests {
         assert!(level.checkpoints.is_empty());
         assert!(level.power_ups.is_empty());
         assert!(level.transitions.is_empty());
+        assert!(level.moving_platforms.is_empty());
+        assert!(level.force_volumes.is_empty());
+        assert!(level.pushables.is_empty());
+        assert!(level.pressure_plates.is_empty());
     }
 
     #[test]
@@ -227,6 +357,37 @@ mod tests {
         assert_eq!(geometry.geometry_type, "platform");
     }
 
+    #[test]
+    fn test_geometry_material_field() {
+        let json = r#"{
+            "type": "platform",
+            "x": 0.0,
+            "y": 0.0,
+            "width": 100.0,
+            "height": 32.0,
+            "material": { "friction": 0.05 }
+        }"#;
+
+        let geometry: GeometryData = serde_json::from_str(json).unwrap();
+        assert_eq!(geometry.material, Some(SurfaceMaterial::ice()));
+    }
+
+    #[test]
+    fn test_slope_geometry_corner_field() {
+        let json = r#"{
+            "type": "triangle",
+            "x": 0.0,
+            "y": 0.0,
+            "width": 100.0,
+            "height": 50.0,
+            "corner": "BottomRight"
+        }"#;
+
+        let geometry: GeometryData = serde_json::from_str(json).unwrap();
+        assert_eq!(geometry.geometry_type, "triangle");
+        assert_eq!(geometry.corner, Some(BoxCorner::BottomRight));
+    }
+
     #[test]
     fn test_power_up_type_field() {
         let json = r#"{
@@ -277,4 +438,42 @@ mod tests {
         assert_eq!(gate.required_ability, Ability::WallClimb);
         assert_eq!(gate.gate_area.x, 1000.0);
     }
+
+    #[test]
+    fn test_moving_platform_data() {
+        let json = r#"{
+            "width": 96.0,
+            "height": 16.0,
+            "waypoints": [{"x": 0.0, "y": 0.0}, {"x": 200.0, "y": 0.0}],
+            "mode": "Loop",
+            "speed": 80.0
+        }"#;
+
+        let platform: MovingPlatformData = serde_json::from_str(json).unwrap();
+        assert_eq!(platform.waypoints.len(), 2);
+        assert_eq!(platform.mode, PathMode::Loop);
+        assert_eq!(platform.pause, 0.0);
+        assert!(!platform.one_way);
+    }
+
+    #[test]
+    fn test_geometry_layers_field() {
+        let json = r#"{
+            "type": "wall",
+            "x": 0.0,
+            "y": 0.0,
+            "width": 32.0,
+            "height": 200.0,
+            "layers": {"layer": 1, "mask": 4}
+        }"#;
+
+        let geometry: GeometryData = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            geometry.layers,
+            Some(CollisionLayers::new(
+                CollisionLayers::DEFAULT,
+                CollisionLayers::ENEMY
+            ))
+        );
+    }
 }
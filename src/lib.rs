@@ -1,8 +1,15 @@
 pub mod components;
 pub mod enums;
+pub mod harness;
 pub mod level;
 pub mod plugins;
+pub mod tuning;
+
+#[cfg(test)]
+mod test_util;
 
 pub use components::*;
 pub use enums::*;
+pub use harness::SimulationHarness;
 pub use level::*;
+pub use tuning::*;
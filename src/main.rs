@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use sidescrolling_adventure_game::plugins::{
-    AbilityPlugin, AnimationPlugin, CameraPlugin, CheckpointPlugin, LevelPlugin, PhysicsPlugin,
-    PlayerPlugin, SwingPlugin,
+    AbilityPlugin, AnimationPlugin, CameraPlugin, CheckpointPlugin, ControlsPlugin, LevelPlugin,
+    PhysicsPlugin, PlayerPlugin, RenderTransformPlugin, ReplayPlugin, SwingPlugin, TuningPlugin,
 };
 
 fn main() {
@@ -15,5 +15,9 @@ fn main() {
         .add_plugins(CheckpointPlugin)
         .add_plugins(AnimationPlugin)
         .add_plugins(CameraPlugin)
+        .add_plugins(RenderTransformPlugin)
+        .add_plugins(TuningPlugin)
+        .add_plugins(ControlsPlugin)
+        .add_plugins(ReplayPlugin)
         .run();
 }
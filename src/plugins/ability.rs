@@ -1,4 +1,5 @@
-use crate::components::{AbilitySet, Collider, Player, Position, PowerUp};
+use crate::components::{AbilitySet, Player, PowerUp};
+use crate::plugins::trigger::TriggerEntered;
 use bevy::prelude::*;
 
 /// Plugin for ability unlocking and usage
@@ -10,42 +11,26 @@ impl Plugin for AbilityPlugin {
     }
 }
 
-/// System to detect and collect power-ups
-fn collect_power_ups_system(
+/// System to collect power-ups the player has entered
+pub(crate) fn collect_power_ups_system(
     mut commands: Commands,
-    mut player_query: Query<(&Position, &Collider, &mut AbilitySet), With<Player>>,
-    power_up_query: Query<(Entity, &Position, &PowerUp)>,
+    mut trigger_events: EventReader<TriggerEntered>,
+    mut player_query: Query<&mut AbilitySet, With<Player>>,
+    power_up_query: Query<&PowerUp>,
 ) {
-    for (player_pos, player_collider, mut ability_set) in player_query.iter_mut() {
-        for (power_up_entity, power_up_pos, power_up) in power_up_query.iter() {
-            // Simple AABB collision detection for power-up collection
-            let player_left = player_pos.x + player_collider.offset_x - player_collider.width / 2.0;
-            let player_right =
-                player_pos.x + player_collider.offset_x + player_collider.width / 2.0;
-            let player_top = player_pos.y + player_collider.offset_y - player_collider.height / 2.0;
-            let player_bottom =
-                player_pos.y + player_collider.offset_y + player_collider.height / 2.0;
-
-            // Assume power-ups have a fixed size of 32x32 for collection
-            let power_up_size = 32.0;
-            let power_up_left = power_up_pos.x - power_up_size / 2.0;
-            let power_up_right = power_up_pos.x + power_up_size / 2.0;
-            let power_up_top = power_up_pos.y - power_up_size / 2.0;
-            let power_up_bottom = power_up_pos.y + power_up_size / 2.0;
-
-            // Check for collision
-            if player_right > power_up_left
-                && player_left < power_up_right
-                && player_bottom > power_up_top
-                && player_top < power_up_bottom
-            {
-                // Add ability to player's set
-                ability_set.add(power_up.ability);
-
-                // Despawn the power-up entity
-                commands.entity(power_up_entity).despawn();
-            }
-        }
+    for event in trigger_events.read() {
+        let (Ok(power_up), Ok(mut ability_set)) = (
+            power_up_query.get(event.trigger),
+            player_query.get_mut(event.entity),
+        ) else {
+            continue;
+        };
+
+        // Add ability to player's set
+        ability_set.add(power_up.ability);
+
+        // Despawn the power-up entity
+        commands.entity(event.trigger).despawn();
     }
 }
 
@@ -54,12 +39,16 @@ mod tests {
     use super::*;
     use crate::components::*;
     use crate::enums::Ability;
+    use crate::plugins::PhysicsPlugin;
+    use crate::plugins::trigger::Trigger;
+    use crate::test_util::run_tick;
 
     #[test]
     fn test_ability_collection_adds_to_set() {
         // Create a test app
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(AbilityPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, AbilityPlugin));
 
         // Spawn player with empty ability set
         let player = app
@@ -67,6 +56,7 @@ mod tests {
             .spawn((
                 Player,
                 Position::new(100.0, 100.0),
+                Velocity::default(),
                 Collider::new(32.0, 64.0),
                 AbilitySet::new(),
             ))
@@ -78,10 +68,12 @@ mod tests {
                 ability: Ability::HighJump,
             },
             Position::new(100.0, 100.0),
+            Trigger,
+            Collider::centered(32.0, 32.0),
         ));
 
-        // Run one update cycle
-        app.update();
+        // Run one fixed tick and the frame after it
+        run_tick(&mut app);
 
         // Verify ability was added
         let ability_set = app.world.get::<AbilitySet>(player).unwrap();
@@ -92,12 +84,14 @@ mod tests {
     fn test_power_up_despawns_after_collection() {
         // Create a test app
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(AbilityPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, AbilityPlugin));
 
         // Spawn player
         app.world.spawn((
             Player,
             Position::new(100.0, 100.0),
+            Velocity::default(),
             Collider::new(32.0, 64.0),
             AbilitySet::new(),
         ));
@@ -110,11 +104,13 @@ mod tests {
                     ability: Ability::HighJump,
                 },
                 Position::new(100.0, 100.0),
+                Trigger,
+                Collider::centered(32.0, 32.0),
             ))
             .id();
 
-        // Run one update cycle
-        app.update();
+        // Run one fixed tick and the frame after it
+        run_tick(&mut app);
 
         // Verify power-up was despawned
         assert!(app.world.get_entity(power_up).is_none());
@@ -124,7 +120,8 @@ mod tests {
     fn test_no_collection_when_far_away() {
         // Create a test app
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(AbilityPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, AbilityPlugin));
 
         // Spawn player
         let player = app
@@ -132,6 +129,7 @@ mod tests {
             .spawn((
                 Player,
                 Position::new(100.0, 100.0),
+                Velocity::default(),
                 Collider::new(32.0, 64.0),
                 AbilitySet::new(),
             ))
@@ -145,11 +143,13 @@ mod tests {
                     ability: Ability::HighJump,
                 },
                 Position::new(500.0, 500.0),
+                Trigger,
+                Collider::centered(32.0, 32.0),
             ))
             .id();
 
-        // Run one update cycle
-        app.update();
+        // Run one fixed tick and the frame after it
+        run_tick(&mut app);
 
         // Verify ability was NOT added
         let ability_set = app.world.get::<AbilitySet>(player).unwrap();
@@ -163,7 +163,8 @@ mod tests {
     fn test_multiple_abilities_collection() {
         // Create a test app
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(AbilityPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, AbilityPlugin));
 
         // Spawn player
         let player = app
@@ -171,6 +172,7 @@ mod tests {
             .spawn((
                 Player,
                 Position::new(100.0, 100.0),
+                Velocity::default(),
                 Collider::new(32.0, 64.0),
                 AbilitySet::new(),
             ))
@@ -182,6 +184,8 @@ mod tests {
                 ability: Ability::HighJump,
             },
             Position::new(100.0, 100.0),
+            Trigger,
+            Collider::centered(32.0, 32.0),
         ));
 
         app.world.spawn((
@@ -189,10 +193,12 @@ mod tests {
                 ability: Ability::WallClimb,
             },
             Position::new(105.0, 105.0),
+            Trigger,
+            Collider::centered(32.0, 32.0),
         ));
 
-        // Run one update cycle
-        app.update();
+        // Run one fixed tick and the frame after it
+        run_tick(&mut app);
 
         // Verify both abilities were added
         let ability_set = app.world.get::<AbilitySet>(player).unwrap();
@@ -0,0 +1,243 @@
+use crate::components::{Collider, LevelGeometry, Position};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Broadphase constants
+pub const SPATIAL_HASH_CELL_SIZE: f32 = 128.0; // Width and height of a grid cell in pixels
+
+/// Uniform grid over level geometry bounds, so collision queries only test nearby geometry
+#[derive(Resource, Clone, Debug)]
+pub struct SpatialHash {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<Entity>>,
+    entries: HashMap<Entity, (IVec2, IVec2)>, // Cell range each entity occupies
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        Self::new(SPATIAL_HASH_CELL_SIZE)
+    }
+}
+
+impl SpatialHash {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Number of geometry entities in the grid
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add geometry to every cell its bounds overlap, replacing any previous entry
+    pub fn insert(&mut self, entity: Entity, geometry: &LevelGeometry) {
+        self.remove(entity);
+
+        let (min_cell, max_cell) = self.cell_range(
+            Vec2::new(geometry.x, geometry.y),
+            Vec2::new(geometry.x + geometry.width, geometry.y + geometry.height),
+        );
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+        self.entries.insert(entity, (min_cell, max_cell));
+    }
+
+    /// Remove geometry from the grid
+    pub fn remove(&mut self, entity: Entity) {
+        let Some((min_cell, max_cell)) = self.entries.remove(&entity) else {
+            return;
+        };
+
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                let cell = IVec2::new(x, y);
+                if let Some(entities) = self.cells.get_mut(&cell) {
+                    entities.retain(|&other| other != entity);
+                    if entities.is_empty() {
+                        self.cells.remove(&cell);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Geometry in any cell overlapping the bounds, sorted so callers stay deterministic
+    pub fn query(&self, min: Vec2, max: Vec2) -> Vec<Entity> {
+        let (min_cell, max_cell) = self.cell_range(min, max);
+        let area = (max_cell.x as i64 - min_cell.x as i64 + 1)
+            * (max_cell.y as i64 - min_cell.y as i64 + 1);
+
+        let mut found: Vec<Entity> = if area > self.cells.len() as i64 {
+            // Huge queries are cheaper as a scan of the occupied cells
+            self.cells
+                .iter()
+                .filter(|(cell, _)| {
+                    (min_cell.x..=max_cell.x).contains(&cell.x)
+                        && (min_cell.y..=max_cell.y).contains(&cell.y)
+                })
+                .flat_map(|(_, entities)| entities.iter().copied())
+                .collect()
+        } else {
+            (min_cell.x..=max_cell.x)
+                .flat_map(|x| (min_cell.y..=max_cell.y).map(move |y| IVec2::new(x, y)))
+                .filter_map(|cell| self.cells.get(&cell))
+                .flat_map(|entities| entities.iter().copied())
+                .collect()
+        };
+
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// Geometry near a body's collider, grown by `reach` on every side
+    pub fn query_around(
+        &self,
+        position: &Position,
+        collider: &Collider,
+        reach: f32,
+    ) -> Vec<Entity> {
+        let min = Vec2::new(
+            position.x + collider.offset_x,
+            position.y + collider.offset_y,
+        );
+        let max = min + Vec2::new(collider.width, collider.height);
+        self.query(min - Vec2::splat(reach), max + Vec2::splat(reach))
+    }
+
+    fn cell_range(&self, min: Vec2, max: Vec2) -> (IVec2, IVec2) {
+        let min_cell = (min / self.cell_size).floor();
+        let max_cell = (max / self.cell_size).floor();
+        (
+            IVec2::new(min_cell.x as i32, min_cell.y as i32),
+            IVec2::new(max_cell.x as i32, max_cell.y as i32),
+        )
+    }
+}
+
+/// Keep the spatial hash in step with added, moved and removed geometry
+pub(crate) fn update_spatial_hash(
+    mut spatial_hash: ResMut<SpatialHash>,
+    changed: Query<(Entity, &LevelGeometry), Changed<LevelGeometry>>,
+    mut removed: RemovedComponents<LevelGeometry>,
+) {
+    for entity in removed.read() {
+        spatial_hash.remove(entity);
+    }
+
+    for (entity, geometry) in changed.iter() {
+        spatial_hash.insert(entity, geometry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_finds_only_nearby_geometry() {
+        let mut world = World::new();
+        let near = world.spawn_empty().id();
+        let far = world.spawn_empty().id();
+
+        let mut hash = SpatialHash::new(100.0);
+        hash.insert(near, &LevelGeometry::new(0.0, 0.0, 50.0, 50.0));
+        hash.insert(far, &LevelGeometry::new(1000.0, 0.0, 50.0, 50.0));
+
+        assert_eq!(
+            hash.query(Vec2::new(10.0, 10.0), Vec2::new(20.0, 20.0)),
+            vec![near]
+        );
+        assert_eq!(
+            hash.query(Vec2::new(500.0, 500.0), Vec2::new(600.0, 600.0)),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_large_geometry_reported_once() {
+        let mut world = World::new();
+        let floor = world.spawn_empty().id();
+
+        let mut hash = SpatialHash::new(100.0);
+        hash.insert(floor, &LevelGeometry::new(-500.0, 0.0, 1000.0, 64.0));
+
+        assert_eq!(
+            hash.query(Vec2::new(-400.0, 0.0), Vec2::new(400.0, 10.0)),
+            vec![floor]
+        );
+    }
+
+    #[test]
+    fn test_touching_geometry_shares_a_cell() {
+        let mut world = World::new();
+        let wall = world.spawn_empty().id();
+
+        let mut hash = SpatialHash::new(100.0);
+        hash.insert(wall, &LevelGeometry::new(0.0, 0.0, 100.0, 100.0));
+
+        let position = Position::new(100.0, 50.0);
+        let collider = Collider::new(32.0, 32.0);
+        assert_eq!(hash.query_around(&position, &collider, 0.0), vec![wall]);
+    }
+
+    #[test]
+    fn test_reinsert_and_remove() {
+        let mut world = World::new();
+        let platform = world.spawn_empty().id();
+
+        let mut hash = SpatialHash::new(100.0);
+        hash.insert(platform, &LevelGeometry::new(0.0, 0.0, 50.0, 50.0));
+        hash.insert(platform, &LevelGeometry::new(300.0, 0.0, 50.0, 50.0));
+        assert_eq!(hash.len(), 1);
+        assert!(hash.query(Vec2::ZERO, Vec2::splat(50.0)).is_empty());
+        assert_eq!(
+            hash.query(Vec2::new(300.0, 0.0), Vec2::new(310.0, 10.0)),
+            vec![platform]
+        );
+
+        hash.remove(platform);
+        assert!(hash.is_empty());
+        assert!(hash.cells.is_empty());
+    }
+
+    #[test]
+    fn test_hash_tracks_spawned_and_despawned_geometry() {
+        let mut app = App::new();
+        app.init_resource::<SpatialHash>()
+            .add_systems(Update, update_spatial_hash);
+
+        let first = app
+            .world
+            .spawn(LevelGeometry::new(0.0, 0.0, 50.0, 50.0))
+            .id();
+        let second = app
+            .world
+            .spawn(LevelGeometry::new(0.0, 0.0, 50.0, 50.0))
+            .id();
+        app.update();
+        assert_eq!(app.world.resource::<SpatialHash>().len(), 2);
+
+        app.world.despawn(first);
+        app.world.get_mut::<LevelGeometry>(second).unwrap().x = 500.0;
+        app.update();
+
+        let hash = app.world.resource::<SpatialHash>();
+        assert_eq!(hash.len(), 1);
+        assert_eq!(
+            hash.query(Vec2::new(500.0, 0.0), Vec2::new(510.0, 10.0)),
+            vec![second]
+        );
+    }
+}
@@ -1,3 +1,8 @@
+use crate::plugins::render_transform::{
+    PreviousPosition, WorldToRender, interpolated_position, render_to_world,
+    sync_render_transforms, world_to_render,
+};
+use crate::plugins::schedule::{GameSet, configure_game_sets};
 use crate::{LevelData, Player, Position};
 use bevy::prelude::*;
 
@@ -14,8 +19,16 @@ pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_camera)
-            .add_systems(PostUpdate, (camera_follow_system, update_camera_projection));
+        configure_game_sets(app);
+        app.add_systems(Startup, setup_camera).add_systems(
+            PostUpdate,
+            (
+                camera_follow_system
+                    .in_set(WorldToRender)
+                    .after(sync_render_transforms),
+                update_camera_projection.in_set(GameSet::Presentation),
+            ),
+        );
     }
 }
 
@@ -29,14 +42,18 @@ fn setup_camera(mut commands: Commands) {
 }
 
 /// Camera follow system - smoothly follows player with lag
+/// Follows and clamps in world space, then converts the result to the camera's render Transform
+/// Aims at the interpolated point the player is drawn at, so the two move together between
+/// fixed ticks
 /// Requirements: 9.1, 9.2, 9.3
 fn camera_follow_system(
     time: Res<Time>,
+    fixed_time: Res<Time<Fixed>>,
     level_data: Option<Res<LevelData>>,
-    player_query: Query<&Position, With<Player>>,
+    player_query: Query<(&Position, Option<&PreviousPosition>), With<Player>>,
     mut camera_query: Query<&mut Transform, With<GameCamera>>,
 ) {
-    let Ok(player_pos) = player_query.get_single() else {
+    let Ok((player_pos, previous)) = player_query.get_single() else {
         return;
     };
 
@@ -44,18 +61,17 @@ fn camera_follow_system(
         return;
     };
 
-    // Target position is the player's position
-    let target_x = player_pos.x;
-    let target_y = player_pos.y;
+    // Target position is where the player is drawn this frame
+    let target = interpolated_position(player_pos, previous, fixed_time.overstep_percentage());
+    let (target_x, target_y) = (target.x, target.y);
+    let camera_pos = render_to_world(camera_transform.translation.truncate());
 
     // Smooth interpolation with lag
     let delta = time.delta_seconds();
     let lerp_factor = 1.0 - (-CAMERA_FOLLOW_SPEED * delta).exp();
 
-    let mut new_x =
-        camera_transform.translation.x + (target_x - camera_transform.translation.x) * lerp_factor;
-    let mut new_y =
-        camera_transform.translation.y + (target_y - camera_transform.translation.y) * lerp_factor;
+    let mut new_x = camera_pos.x + (target_x - camera_pos.x) * lerp_factor;
+    let mut new_y = camera_pos.y + (target_y - camera_pos.y) * lerp_factor;
 
     // Apply camera bounds constraint if level data is available
     if let Some(level) = level_data {
@@ -89,8 +105,9 @@ fn camera_follow_system(
         }
     }
 
-    camera_transform.translation.x = new_x;
-    camera_transform.translation.y = new_y;
+    let render = world_to_render(Vec2::new(new_x, new_y));
+    camera_transform.translation.x = render.x;
+    camera_transform.translation.y = render.y;
 }
 
 /// Update camera projection to maintain consistent viewport scale
@@ -161,11 +178,11 @@ mod tests {
         let mut camera_query = app.world.query_filtered::<&Transform, With<GameCamera>>();
         let camera_transform = camera_query.iter(&app.world).next().unwrap();
 
-        // Camera should be closer to player position (500, 300) than initial position (0, 0)
+        // Camera should be closer to the player, rendered at (500, -300), than initial position (0, 0)
         let distance_to_player = ((camera_transform.translation.x - 500.0).powi(2)
-            + (camera_transform.translation.y - 300.0).powi(2))
+            + (camera_transform.translation.y + 300.0).powi(2))
         .sqrt();
-        let initial_distance = ((initial_x - 500.0).powi(2) + (initial_y - 300.0).powi(2)).sqrt();
+        let initial_distance = ((initial_x - 500.0).powi(2) + (initial_y + 300.0).powi(2)).sqrt();
 
         assert!(
             distance_to_player < initial_distance,
@@ -228,6 +245,10 @@ mod tests {
             power_ups: vec![],
             transitions: vec![],
             ability_gates: vec![],
+            moving_platforms: vec![],
+            force_volumes: vec![],
+            pushables: vec![],
+            pressure_plates: vec![],
         };
         app.insert_resource(level);
 
@@ -276,6 +297,10 @@ mod tests {
             power_ups: vec![],
             transitions: vec![],
             ability_gates: vec![],
+            moving_platforms: vec![],
+            force_volumes: vec![],
+            pushables: vec![],
+            pressure_plates: vec![],
         };
         app.insert_resource(level);
 
@@ -299,6 +324,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_camera_bounds_constraint_bottom_edge() {
+        use crate::level::*;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(CameraPlugin);
+
+        let level = LevelData {
+            id: "test_level".to_string(),
+            width: 2000.0,
+            height: 1500.0,
+            spawn_point: SpawnPoint {
+                x: 1000.0,
+                y: 750.0,
+            },
+            geometry: vec![],
+            swing_points: vec![],
+            checkpoints: vec![],
+            power_ups: vec![],
+            transitions: vec![],
+            ability_gates: vec![],
+            moving_platforms: vec![],
+            force_volumes: vec![],
+            pushables: vec![],
+            pressure_plates: vec![],
+        };
+        app.insert_resource(level);
+
+        // Spawn player below the level's bottom edge (world y grows downward)
+        app.world.spawn((Player, Position::new(1000.0, 2000.0)));
+        app.update();
+
+        // Start the camera near the player so one step reaches the bound
+        let mut camera_query = app
+            .world
+            .query_filtered::<&mut Transform, With<GameCamera>>();
+        camera_query.single_mut(&mut app.world).translation.y = -1900.0;
+        app.update();
+
+        let mut camera_query = app.world.query_filtered::<&Transform, With<GameCamera>>();
+        let camera_transform = camera_query.iter(&app.world).next().unwrap();
+
+        // The lowest camera center is 1500 - 360 = 1140 in world space, -1140 when rendered
+        assert!(
+            (camera_transform.translation.y + 1140.0).abs() < 1.0,
+            "Camera Y should stop at the level's bottom edge, got {}",
+            camera_transform.translation.y
+        );
+    }
+
     #[test]
     fn test_camera_without_level_data() {
         let mut app = App::new();
@@ -344,6 +420,10 @@ mod tests {
             power_ups: vec![],
             transitions: vec![],
             ability_gates: vec![],
+            moving_platforms: vec![],
+            force_volumes: vec![],
+            pushables: vec![],
+            pressure_plates: vec![],
         };
         app.insert_resource(level);
 
@@ -359,14 +439,14 @@ mod tests {
         let mut camera_query = app.world.query_filtered::<&Transform, With<GameCamera>>();
         let camera_transform = camera_query.iter(&app.world).next().unwrap();
 
-        // Camera should be at center of level (400, 300)
+        // Camera should be at center of level (400, 300), rendered at (400, -300)
         assert!(
             (camera_transform.translation.x - 400.0).abs() < 1.0,
             "Camera X should be centered on small level, got {}",
             camera_transform.translation.x
         );
         assert!(
-            (camera_transform.translation.y - 300.0).abs() < 1.0,
+            (camera_transform.translation.y + 300.0).abs() < 1.0,
             "Camera Y should be centered on small level, got {}",
             camera_transform.translation.y
         );
@@ -432,4 +512,48 @@ mod tests {
             "Projection scale should be positive"
         );
     }
+
+    fn move_right(mut query: Query<&mut Position>) {
+        for mut position in query.iter_mut() {
+            position.x += 10.0;
+        }
+    }
+
+    #[test]
+    fn test_camera_follows_interpolated_player() {
+        use crate::plugins::RenderTransformPlugin;
+        use bevy::time::TimeUpdateStrategy;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((CameraPlugin, RenderTransformPlugin));
+        // One and a half ticks per frame leaves the fixed clock halfway to its next tick
+        let timestep = app.world.resource::<Time<Fixed>>().timestep();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep.mul_f64(1.5)))
+            .add_systems(FixedUpdate, move_right.in_set(GameSet::Movement));
+        let player = app
+            .world
+            .spawn((
+                Player,
+                Position::new(0.0, 0.0),
+                PreviousPosition(Position::new(0.0, 0.0)),
+                Transform::default(),
+            ))
+            .id();
+
+        // Start the camera on the point the player will be drawn at after one tick
+        app.update();
+        let mut camera_query = app
+            .world
+            .query_filtered::<&mut Transform, With<GameCamera>>();
+        camera_query.single_mut(&mut app.world).translation.x = 5.0;
+        app.update();
+
+        // The player is at 10 but drawn halfway there, and the camera stays on the drawn point
+        assert_eq!(app.world.get::<Position>(player).unwrap().x, 10.0);
+        let rendered = app.world.get::<Transform>(player).unwrap().translation.x;
+        let camera = camera_query.single(&app.world).translation.x;
+        assert!((rendered - 5.0).abs() < 0.01, "Player drawn at {rendered}");
+        assert!((camera - rendered).abs() < 0.01, "Camera at {camera}");
+    }
 }
@@ -1,9 +1,14 @@
-use crate::components::{AbilitySet, Player, Position};
+use crate::components::{AbilitySet, Collider, Player, Position};
+use crate::plugins::render_transform::Teleported;
+use crate::plugins::trigger::{Trigger, TriggerEntered};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+/// Width and height of the area that activates a checkpoint
+pub const CHECKPOINT_SIZE: f32 = 128.0;
+
 /// Checkpoint component - marks an entity as a checkpoint
 #[derive(Component, Clone, Debug, PartialEq)]
 pub struct Checkpoint {
@@ -12,12 +17,23 @@ pub struct Checkpoint {
 }
 
 impl Checkpoint {
-    pub fn new(id: String) -> Self {
+    /// Only used through bundle, since a checkpoint without its trigger never activates
+    fn new(id: String) -> Self {
         Self {
             id,
             activated: false,
         }
     }
+
+    /// Checkpoint with the trigger area that activates it, centered on its position
+    pub fn bundle(id: String, position: Position) -> (Self, Trigger, Position, Collider) {
+        (
+            Self::new(id),
+            Trigger,
+            position,
+            Collider::centered(CHECKPOINT_SIZE, CHECKPOINT_SIZE),
+        )
+    }
 }
 
 /// Game state that can be saved and loaded
@@ -112,33 +128,25 @@ impl Plugin for CheckpointPlugin {
     }
 }
 
-/// System to detect checkpoint activation
+/// System to activate checkpoints the player has entered
 fn checkpoint_activation_system(
-    mut checkpoint_query: Query<(&mut Checkpoint, &Position)>,
-    player_query: Query<&Position, With<Player>>,
+    mut trigger_events: EventReader<TriggerEntered>,
+    mut checkpoint_query: Query<&mut Checkpoint>,
+    player_query: Query<(), With<Player>>,
     mut checkpoint_events: EventWriter<CheckpointActivated>,
 ) {
-    let Ok(player_pos) = player_query.get_single() else {
-        return;
-    };
-
-    for (mut checkpoint, checkpoint_pos) in checkpoint_query.iter_mut() {
-        if checkpoint.activated {
+    for event in trigger_events.read() {
+        let Ok(mut checkpoint) = checkpoint_query.get_mut(event.trigger) else {
+            continue;
+        };
+        if checkpoint.activated || !player_query.contains(event.entity) {
             continue;
         }
 
-        // Check if player is within activation range (64 pixels)
-        let dx = player_pos.x - checkpoint_pos.x;
-        let dy = player_pos.y - checkpoint_pos.y;
-        let distance_squared = dx * dx + dy * dy;
-        let activation_range = 64.0;
-
-        if distance_squared <= activation_range * activation_range {
-            checkpoint.activated = true;
-            checkpoint_events.send(CheckpointActivated {
-                checkpoint_id: checkpoint.id.clone(),
-            });
-        }
+        checkpoint.activated = true;
+        checkpoint_events.send(CheckpointActivated {
+            checkpoint_id: checkpoint.id.clone(),
+        });
     }
 }
 
@@ -171,9 +179,10 @@ fn checkpoint_save_system(
 
 /// System to restore game state from checkpoint
 fn checkpoint_restore_system(
+    mut commands: Commands,
     mut restore_events: EventReader<RestoreCheckpoint>,
     current_state: Res<CurrentGameState>,
-    mut player_query: Query<(&mut Position, &mut AbilitySet), With<Player>>,
+    mut player_query: Query<(Entity, &mut Position, &mut AbilitySet), With<Player>>,
 ) {
     for _ in restore_events.read() {
         let Some(ref game_state) = current_state.state else {
@@ -181,7 +190,7 @@ fn checkpoint_restore_system(
             continue;
         };
 
-        let Ok((mut player_pos, mut abilities)) = player_query.get_single_mut() else {
+        let Ok((player, mut player_pos, mut abilities)) = player_query.get_single_mut() else {
             warn!("Player not found for checkpoint restore");
             continue;
         };
@@ -189,6 +198,7 @@ fn checkpoint_restore_system(
         // Restore player position and abilities
         *player_pos = game_state.checkpoint_position;
         *abilities = game_state.unlocked_abilities.clone();
+        commands.entity(player).insert(Teleported);
 
         info!("Restored from checkpoint: {}", game_state.checkpoint_id);
     }
@@ -276,12 +286,58 @@ fn load_from_disk_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::Velocity;
+    use crate::test_util::run_tick;
 
     #[test]
     fn test_checkpoint_creation() {
-        let checkpoint = Checkpoint::new("cp_01".to_string());
+        let (checkpoint, _, position, collider) =
+            Checkpoint::bundle("cp_01".to_string(), Position::new(100.0, 200.0));
         assert_eq!(checkpoint.id, "cp_01");
         assert!(!checkpoint.activated);
+        assert_eq!(position, Position::new(100.0, 200.0));
+        assert_eq!(
+            collider,
+            Collider::centered(CHECKPOINT_SIZE, CHECKPOINT_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_activated_when_player_enters() {
+        let save_dir = tempfile::tempdir().unwrap();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((crate::plugins::PhysicsPlugin, CheckpointPlugin))
+            .insert_resource(SaveFilePath {
+                path: save_dir.path().join("save.json"),
+            });
+
+        let near = app
+            .world
+            .spawn(Checkpoint::bundle(
+                "cp_near".to_string(),
+                Position::new(200.0, 200.0),
+            ))
+            .id();
+        let far = app
+            .world
+            .spawn(Checkpoint::bundle(
+                "cp_far".to_string(),
+                Position::new(800.0, 200.0),
+            ))
+            .id();
+        app.world.spawn((
+            Player,
+            Position::new(150.0, 150.0),
+            Velocity::default(),
+            Collider::new(32.0, 64.0),
+            AbilitySet::new(),
+        ));
+
+        run_tick(&mut app);
+
+        assert!(app.world.get::<Checkpoint>(near).unwrap().activated);
+        assert!(!app.world.get::<Checkpoint>(far).unwrap().activated);
     }
 
     #[test]
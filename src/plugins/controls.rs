@@ -0,0 +1,581 @@
+use crate::enums::{AxisDirection, InputAction};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One way of triggering an action
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    GamepadButton(GamepadButtonType), // On any connected gamepad
+    GamepadAxis(GamepadAxisType, AxisDirection), // Pushed past the deadzone, on any gamepad
+}
+
+/// Which keys, buttons and sticks trigger each action
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionMap {
+    #[serde(deserialize_with = "deserialize_over_defaults")]
+    pub bindings: BTreeMap<InputAction, Vec<InputBinding>>, // Actions missing from a file keep their defaults
+    pub stick_deadzone: f32, // Stick deflection (0 to 1) at or below this is ignored
+}
+
+impl ActionMap {
+    /// Bindings of an action, empty if it is unbound
+    pub fn bindings_for(&self, action: InputAction) -> &[InputBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Replace every binding of an action
+    pub fn rebind(&mut self, action: InputAction, bindings: Vec<InputBinding>) {
+        self.bindings.insert(action, bindings);
+    }
+}
+
+impl Default for ActionMap {
+    fn default() -> Self {
+        use InputBinding::{GamepadAxis, GamepadButton, Key};
+
+        let bindings = BTreeMap::from([
+            (
+                InputAction::MoveLeft,
+                vec![
+                    Key(KeyCode::Left),
+                    Key(KeyCode::A),
+                    GamepadButton(GamepadButtonType::DPadLeft),
+                    GamepadAxis(GamepadAxisType::LeftStickX, AxisDirection::Negative),
+                ],
+            ),
+            (
+                InputAction::MoveRight,
+                vec![
+                    Key(KeyCode::Right),
+                    Key(KeyCode::D),
+                    GamepadButton(GamepadButtonType::DPadRight),
+                    GamepadAxis(GamepadAxisType::LeftStickX, AxisDirection::Positive),
+                ],
+            ),
+            (
+                InputAction::Up,
+                vec![
+                    Key(KeyCode::Up),
+                    Key(KeyCode::W),
+                    GamepadButton(GamepadButtonType::DPadUp),
+                    GamepadAxis(GamepadAxisType::LeftStickY, AxisDirection::Positive),
+                ],
+            ),
+            (
+                InputAction::Down,
+                vec![
+                    Key(KeyCode::Down),
+                    Key(KeyCode::S),
+                    GamepadButton(GamepadButtonType::DPadDown),
+                    GamepadAxis(GamepadAxisType::LeftStickY, AxisDirection::Negative),
+                ],
+            ),
+            (
+                InputAction::Jump,
+                vec![Key(KeyCode::Space), GamepadButton(GamepadButtonType::South)],
+            ),
+            (
+                InputAction::Swing,
+                vec![Key(KeyCode::E), GamepadButton(GamepadButtonType::West)],
+            ),
+            (
+                InputAction::Dash,
+                vec![
+                    Key(KeyCode::ShiftLeft),
+                    GamepadButton(GamepadButtonType::RightTrigger),
+                ],
+            ),
+            (
+                InputAction::Pause,
+                vec![
+                    Key(KeyCode::Escape),
+                    GamepadButton(GamepadButtonType::Start),
+                ],
+            ),
+        ]);
+
+        Self {
+            bindings,
+            stick_deadzone: 0.3,
+        }
+    }
+}
+
+/// Read bindings on top of the defaults, so a controls file only needs the actions it remaps
+fn deserialize_over_defaults<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<InputAction, Vec<InputBinding>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut bindings = ActionMap::default().bindings;
+    bindings.extend(BTreeMap::deserialize(deserializer)?);
+    Ok(bindings)
+}
+
+/// Actions held and changed this frame, read from every device in the ActionMap
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct ActionState {
+    held: HashSet<InputAction>,
+    just_pressed: HashSet<InputAction>,
+    just_released: HashSet<InputAction>,
+    strength: HashMap<InputAction, f32>,
+}
+
+impl ActionState {
+    /// Whether the action is held, or was pressed at any point this frame
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.held.contains(&action) || self.just_pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    pub fn just_released(&self, action: InputAction) -> bool {
+        self.just_released.contains(&action)
+    }
+
+    /// How far the action is pushed, from 0 to 1
+    /// Keys and buttons are 1 while pressed; sticks ramp up from the edge of the deadzone
+    pub fn strength(&self, action: InputAction) -> f32 {
+        match self.strength.get(&action) {
+            Some(strength) => *strength,
+            None if self.just_pressed.contains(&action) => 1.0, // Tapped within the frame
+            None => 0.0,
+        }
+    }
+}
+
+/// Resource naming the controls file bindings are loaded from and saved to
+#[derive(Resource, Clone, Debug)]
+pub struct ControlsFilePath {
+    pub path: PathBuf,
+}
+
+impl Default for ControlsFilePath {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("controls.ron"),
+        }
+    }
+}
+
+/// Event to write the current ActionMap to the controls file, e.g. after remapping
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct SaveControls;
+
+/// Plugin that loads the ActionMap from the controls file at startup and saves it on request
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActionMap>()
+            .init_resource::<ControlsFilePath>()
+            .add_event::<SaveControls>()
+            .add_systems(Startup, load_controls_system)
+            .add_systems(Last, save_controls_system);
+    }
+}
+
+/// Read every bound device into the ActionState
+/// Devices without their input resource, such as gamepads in a headless app, are skipped
+pub(crate) fn update_action_state(
+    map: Res<ActionMap>,
+    mut state: ResMut<ActionState>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    buttons: Option<Res<Input<GamepadButton>>>,
+    axes: Option<Res<Axis<GamepadAxis>>>,
+) {
+    let previous = std::mem::take(&mut state.held);
+    state.just_pressed.clear();
+    state.just_released.clear();
+    state.strength.clear();
+
+    for (&action, bindings) in &map.bindings {
+        let mut held = false;
+        let mut strength: f32 = 0.0;
+        let mut pressed = false; // Pressed this frame, even if already released again
+        let mut released = false;
+
+        for binding in bindings {
+            match *binding {
+                InputBinding::Key(key) => {
+                    if let Some(keyboard) = &keyboard {
+                        if keyboard.pressed(key) {
+                            held = true;
+                            strength = 1.0;
+                        }
+                        pressed |= keyboard.just_pressed(key);
+                        released |= keyboard.just_released(key);
+                    }
+                }
+                InputBinding::GamepadButton(button_type) => {
+                    if let Some(buttons) = &buttons {
+                        let matches = |button: &GamepadButton| button.button_type == button_type;
+                        if buttons.get_pressed().any(matches) {
+                            held = true;
+                            strength = 1.0;
+                        }
+                        pressed |= buttons.get_just_pressed().any(matches);
+                        released |= buttons.get_just_released().any(matches);
+                    }
+                }
+                InputBinding::GamepadAxis(axis_type, direction) => {
+                    if let Some(axes) = &axes {
+                        let deflection = axes
+                            .devices()
+                            .filter(|axis| axis.axis_type == axis_type)
+                            .filter_map(|axis| axes.get(*axis))
+                            .map(|value| match direction {
+                                AxisDirection::Positive => value,
+                                AxisDirection::Negative => -value,
+                            })
+                            .fold(0.0, f32::max);
+                        if deflection > map.stick_deadzone {
+                            held = true;
+                            let range = (1.0 - map.stick_deadzone).max(f32::EPSILON);
+                            strength =
+                                strength.max(((deflection - map.stick_deadzone) / range).min(1.0));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Sticks have no press events, so presses and releases also come from held changing
+        let was_held = previous.contains(&action);
+        if held {
+            state.held.insert(action);
+            state.strength.insert(action, strength);
+        }
+        if pressed || (held && !was_held) {
+            state.just_pressed.insert(action);
+        }
+        // Letting go of one binding while another is still held is not a release
+        if !held && (released || was_held) {
+            state.just_released.insert(action);
+        }
+    }
+}
+
+/// Load the controls file if there is one, keeping the default bindings otherwise
+fn load_controls_system(file: Res<ControlsFilePath>, mut map: ResMut<ActionMap>) {
+    if !file.path.exists() {
+        return;
+    }
+
+    match load_action_map(&file.path) {
+        Ok(loaded) => {
+            info!("Loaded controls from {}", file.path.display());
+            *map = loaded;
+        }
+        Err(e) => {
+            error!("{}", e);
+        }
+    }
+}
+
+/// Save the ActionMap whenever SaveControls is sent
+fn save_controls_system(
+    mut save_events: EventReader<SaveControls>,
+    file: Res<ControlsFilePath>,
+    map: Res<ActionMap>,
+) {
+    if save_events.read().count() == 0 {
+        return;
+    }
+
+    match save_action_map(&file.path, &map) {
+        Ok(()) => info!("Saved controls to {}", file.path.display()),
+        Err(e) => error!("{}", e),
+    }
+}
+
+/// Load bindings from a RON controls file
+pub fn load_action_map(path: &Path) -> Result<ActionMap, ControlsError> {
+    let display = path.display().to_string();
+    if !path.exists() {
+        return Err(ControlsError::FileNotFound(display));
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|e| ControlsError::IoError(display.clone(), e.to_string()))?;
+    ron::from_str(&contents).map_err(|e| ControlsError::ParseError(display, e.to_string()))
+}
+
+/// Write bindings to a RON controls file
+pub fn save_action_map(path: &Path, map: &ActionMap) -> Result<(), ControlsError> {
+    let display = path.display().to_string();
+    let contents = ron::ser::to_string_pretty(map, ron::ser::PrettyConfig::default())
+        .map_err(|e| ControlsError::ParseError(display.clone(), e.to_string()))?;
+    fs::write(path, contents).map_err(|e| ControlsError::IoError(display, e.to_string()))
+}
+
+/// Controls file errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ControlsError {
+    FileNotFound(String),
+    IoError(String, String),
+    ParseError(String, String),
+}
+
+impl std::fmt::Display for ControlsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ControlsError::FileNotFound(path) => write!(f, "Controls file not found: {}", path),
+            ControlsError::IoError(path, err) => {
+                write!(f, "IO error accessing controls file {}: {}", path, err)
+            }
+            ControlsError::ParseError(path, err) => {
+                write!(f, "Failed to parse controls file {}: {}", path, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ControlsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn controls_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ActionMap>()
+            .init_resource::<ActionState>()
+            .init_resource::<Input<KeyCode>>()
+            .init_resource::<Input<GamepadButton>>()
+            .init_resource::<Axis<GamepadAxis>>()
+            .add_systems(PreUpdate, update_action_state);
+        app
+    }
+
+    /// Run a frame, then clear the devices' per-frame events like bevy's input systems do
+    fn run_frame(app: &mut App) {
+        app.update();
+        app.world.resource_mut::<Input<KeyCode>>().clear();
+        app.world.resource_mut::<Input<GamepadButton>>().clear();
+    }
+
+    fn set_stick(app: &mut App, value: f32) {
+        app.world.resource_mut::<Axis<GamepadAxis>>().set(
+            GamepadAxis::new(Gamepad::new(0), GamepadAxisType::LeftStickX),
+            value,
+        );
+    }
+
+    #[test]
+    fn test_key_press_hold_and_release() {
+        let mut app = controls_app();
+
+        app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::A);
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!(state.pressed(InputAction::MoveLeft));
+        assert!(state.just_pressed(InputAction::MoveLeft));
+        assert!(!state.pressed(InputAction::MoveRight));
+
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!(state.pressed(InputAction::MoveLeft));
+        assert!(!state.just_pressed(InputAction::MoveLeft));
+
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .release(KeyCode::A);
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!(!state.pressed(InputAction::MoveLeft));
+        assert!(state.just_released(InputAction::MoveLeft));
+
+        run_frame(&mut app);
+        assert!(
+            !app.world
+                .resource::<ActionState>()
+                .just_released(InputAction::MoveLeft)
+        );
+    }
+
+    #[test]
+    fn test_tap_within_one_frame_counts_as_pressed_once() {
+        let mut app = controls_app();
+
+        let mut keyboard = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        keyboard.release(KeyCode::Space);
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!(state.pressed(InputAction::Jump));
+        assert!(state.just_released(InputAction::Jump));
+
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!(!state.pressed(InputAction::Jump));
+        assert!(!state.just_released(InputAction::Jump));
+    }
+
+    #[test]
+    fn test_gamepad_button_triggers_action() {
+        let mut app = controls_app();
+
+        app.world
+            .resource_mut::<Input<GamepadButton>>()
+            .press(GamepadButton::new(
+                Gamepad::new(1),
+                GamepadButtonType::South,
+            ));
+        run_frame(&mut app);
+
+        assert!(
+            app.world
+                .resource::<ActionState>()
+                .just_pressed(InputAction::Jump)
+        );
+    }
+
+    #[test]
+    fn test_stick_respects_deadzone() {
+        let mut app = controls_app();
+
+        set_stick(&mut app, -0.2);
+        run_frame(&mut app);
+        assert!(
+            !app.world
+                .resource::<ActionState>()
+                .pressed(InputAction::MoveLeft)
+        );
+
+        set_stick(&mut app, -0.8);
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!(state.just_pressed(InputAction::MoveLeft));
+        assert!(!state.pressed(InputAction::MoveRight));
+
+        app.world.resource_mut::<ActionMap>().stick_deadzone = 0.9;
+        run_frame(&mut app);
+        assert!(
+            app.world
+                .resource::<ActionState>()
+                .just_released(InputAction::MoveLeft)
+        );
+    }
+
+    #[test]
+    fn test_strength_ramps_from_deadzone() {
+        let mut app = controls_app();
+
+        set_stick(&mut app, -0.65);
+        run_frame(&mut app);
+        let state = app.world.resource::<ActionState>();
+        assert!((state.strength(InputAction::MoveLeft) - 0.5).abs() < 0.001);
+        assert_eq!(state.strength(InputAction::MoveRight), 0.0);
+
+        set_stick(&mut app, 0.0);
+        app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::A);
+        run_frame(&mut app);
+        assert_eq!(
+            app.world
+                .resource::<ActionState>()
+                .strength(InputAction::MoveLeft),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_rebound_action_ignores_old_keys() {
+        let mut app = controls_app();
+        app.world
+            .resource_mut::<ActionMap>()
+            .rebind(InputAction::Jump, vec![InputBinding::Key(KeyCode::K)]);
+
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Space);
+        run_frame(&mut app);
+        assert!(
+            !app.world
+                .resource::<ActionState>()
+                .pressed(InputAction::Jump)
+        );
+
+        app.world.resource_mut::<Input<KeyCode>>().press(KeyCode::K);
+        run_frame(&mut app);
+        assert!(
+            app.world
+                .resource::<ActionState>()
+                .pressed(InputAction::Jump)
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_action_map() {
+        let mut map = ActionMap::default();
+        map.rebind(
+            InputAction::Dash,
+            vec![
+                InputBinding::Key(KeyCode::X),
+                InputBinding::GamepadAxis(GamepadAxisType::RightZ, AxisDirection::Positive),
+            ],
+        );
+        map.stick_deadzone = 0.15;
+        let file = NamedTempFile::with_suffix(".ron").unwrap();
+
+        save_action_map(file.path(), &map).unwrap();
+        assert_eq!(load_action_map(file.path()), Ok(map));
+    }
+
+    #[test]
+    fn test_partial_controls_file_keeps_defaults() {
+        let mut file = NamedTempFile::with_suffix(".ron").unwrap();
+        write!(file, "(stick_deadzone: 0.5)").unwrap();
+
+        let map = load_action_map(file.path()).unwrap();
+        assert_eq!(map.stick_deadzone, 0.5);
+        assert_eq!(map.bindings, ActionMap::default().bindings);
+    }
+
+    #[test]
+    fn test_plugin_loads_and_saves_controls_file() {
+        let mut file = NamedTempFile::with_suffix(".ron").unwrap();
+        write!(file, "(bindings: {{ Jump: [Key(J)] }})").unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(ControlsPlugin)
+            .insert_resource(ControlsFilePath {
+                path: file.path().to_path_buf(),
+            });
+        app.update();
+        assert_eq!(
+            app.world
+                .resource::<ActionMap>()
+                .bindings_for(InputAction::Jump),
+            &[InputBinding::Key(KeyCode::J)]
+        );
+
+        app.world
+            .resource_mut::<ActionMap>()
+            .rebind(InputAction::Pause, vec![InputBinding::Key(KeyCode::P)]);
+        app.world.send_event(SaveControls);
+        app.update();
+
+        let saved = load_action_map(file.path()).unwrap();
+        assert_eq!(
+            saved.bindings_for(InputAction::Pause),
+            &[InputBinding::Key(KeyCode::P)]
+        );
+        assert_eq!(
+            saved.bindings_for(InputAction::MoveLeft),
+            ActionMap::default().bindings_for(InputAction::MoveLeft)
+        );
+    }
+}
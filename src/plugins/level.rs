@@ -1,8 +1,14 @@
 use crate::components::{
-    AbilitySet, Collider, LevelGeometry, Player, Position, PowerUp, SwingPoint,
+    AbilitySet, Collider, CollisionLayers, ForceVolume, GeometryShape, LevelGeometry, Player,
+    Position, PowerUp, SwingPoint,
 };
 use crate::enums::Ability;
-use crate::level::LevelData;
+use crate::level::{GeometryData, LevelData};
+use crate::plugins::platform::MovingPlatform;
+use crate::plugins::pushable::{PressurePlate, Pushable};
+use crate::plugins::render_transform::Teleported;
+use crate::plugins::swing::{MAX_ROPE_LENGTH, MIN_ROPE_LENGTH};
+use crate::plugins::trigger::{Trigger, TriggerEntered};
 use bevy::prelude::*;
 use std::fs;
 use std::path::Path;
@@ -26,7 +32,6 @@ pub struct PendingTransition {
 pub struct LevelTransitionTrigger {
     pub to_level: String,
     pub spawn_point: Position,
-    pub trigger_area: Collider,
 }
 
 /// Component to mark ability-gated areas
@@ -103,28 +108,176 @@ fn validate_level_data(level: &LevelData) -> Result<(), LevelLoadError> {
                 i
             )));
         }
+
+        if matches!(geo.geometry_type.as_str(), "triangle" | "ramp") && geo.corner.is_none() {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Geometry {} of type {} requires a corner",
+                i, geo.geometry_type
+            )));
+        }
+    }
+
+    // Validate moving platforms
+    for (i, platform) in level.moving_platforms.iter().enumerate() {
+        if platform.width <= 0.0 || platform.height <= 0.0 {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Moving platform {} has invalid dimensions",
+                i
+            )));
+        }
+
+        if platform.waypoints.is_empty() {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Moving platform {} has no waypoints",
+                i
+            )));
+        }
+
+        if platform.speed <= 0.0 || platform.pause < 0.0 {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Moving platform {} has invalid speed or pause",
+                i
+            )));
+        }
+    }
+
+    // Validate pushables
+    for (i, pushable) in level.pushables.iter().enumerate() {
+        if pushable.width <= 0.0 || pushable.height <= 0.0 || pushable.mass <= 0.0 {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Pushable {} has invalid dimensions or mass",
+                i
+            )));
+        }
+    }
+
+    // Validate pressure plates
+    for (i, plate) in level.pressure_plates.iter().enumerate() {
+        if plate.width <= 0.0 || plate.height <= 0.0 {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Pressure plate {} has invalid dimensions",
+                i
+            )));
+        }
+    }
+
+    // Validate force volumes
+    for (i, volume) in level.force_volumes.iter().enumerate() {
+        if volume.width <= 0.0 || volume.height <= 0.0 {
+            return Err(LevelLoadError::ValidationError(format!(
+                "Force volume {} has invalid dimensions",
+                i
+            )));
+        }
     }
 
     Ok(())
 }
 
+/// Build collision geometry from level data, mapping slope and one-way types
+pub fn geometry_from_data(geo: &GeometryData) -> LevelGeometry {
+    let shape = match (geo.geometry_type.as_str(), geo.corner) {
+        ("triangle", Some(corner)) => GeometryShape::Triangle(corner),
+        ("ramp", Some(corner)) => GeometryShape::Ramp(corner),
+        _ => GeometryShape::Box,
+    };
+
+    LevelGeometry {
+        shape,
+        one_way: geo.geometry_type == "oneway",
+        layers: geo.layers.unwrap_or_default(),
+        ..LevelGeometry::new(geo.x, geo.y, geo.width, geo.height)
+    }
+}
+
+/// Matches everything spawn_level_entities spawns, for unloading a level
+pub type LevelEntityFilter = Or<(
+    With<LevelGeometry>,
+    With<LevelTransitionTrigger>,
+    With<AbilityGate>,
+    With<PowerUp>,
+    With<SwingPoint>,
+    With<ForceVolume>,
+    With<PressurePlate>,
+)>;
+
 /// Spawn level entities from level data
+/// Everything the render layer places gets a Transform, which it moves into place each frame
 pub fn spawn_level_entities(commands: &mut Commands, level: &LevelData) {
     // Spawn geometry
     for geo in &level.geometry {
-        commands.spawn(LevelGeometry {
-            x: geo.x,
-            y: geo.y,
-            width: geo.width,
-            height: geo.height,
-        });
+        let mut entity = commands.spawn((geometry_from_data(geo), Transform::default()));
+        if let Some(material) = geo.material {
+            entity.insert(material);
+        }
+    }
+
+    // Spawn moving platforms at their first waypoint
+    for platform in &level.moving_platforms {
+        let waypoints: Vec<Vec2> = platform
+            .waypoints
+            .iter()
+            .map(|waypoint| Vec2::new(waypoint.x, waypoint.y))
+            .collect();
+        let Some(start) = waypoints.first().copied() else {
+            continue;
+        };
+
+        commands.spawn((
+            LevelGeometry {
+                one_way: platform.one_way,
+                layers: platform.layers.unwrap_or_default(),
+                ..LevelGeometry::new(start.x, start.y, platform.width, platform.height)
+            },
+            MovingPlatform::new(waypoints, platform.mode, platform.speed, platform.pause),
+            Transform::default(),
+        ));
+    }
+
+    // Spawn pushables
+    for pushable in &level.pushables {
+        commands.spawn((
+            Pushable::bundle(
+                pushable.mass,
+                Position::new(pushable.x, pushable.y),
+                pushable.width,
+                pushable.height,
+            ),
+            Transform::default(),
+        ));
+    }
+
+    // Spawn pressure plates
+    for plate in &level.pressure_plates {
+        commands.spawn((
+            PressurePlate::new(plate.id.clone()),
+            Position::new(plate.x, plate.y),
+            Collider::new(plate.width, plate.height),
+            Transform::default(),
+        ));
+    }
+
+    // Spawn force volumes
+    for volume in &level.force_volumes {
+        commands.spawn(ForceVolume::new(
+            volume.x,
+            volume.y,
+            volume.width,
+            volume.height,
+            volume.effect,
+        ));
     }
 
     // Spawn swing points
     for swing_point in &level.swing_points {
         commands.spawn((
-            SwingPoint { range: 100.0 },
+            SwingPoint {
+                range: 100.0,
+                min_length: swing_point.min_length.unwrap_or(MIN_ROPE_LENGTH),
+                max_length: swing_point.max_length.unwrap_or(MAX_ROPE_LENGTH),
+            },
             Position::new(swing_point.x, swing_point.y),
+            Transform::default(),
         ));
     }
 
@@ -135,21 +288,27 @@ pub fn spawn_level_entities(commands: &mut Commands, level: &LevelData) {
                 ability: power_up.ability_type,
             },
             Position::new(power_up.x, power_up.y),
+            Trigger,
+            Collider::centered(32.0, 32.0), // Power-ups are 32x32 around their position
+            Transform::default(),
         ));
     }
 
     // Spawn level transition triggers
     for transition in &level.transitions {
-        commands.spawn(LevelTransitionTrigger {
-            to_level: transition.to_level.clone(),
-            spawn_point: Position::new(transition.spawn_point.x, transition.spawn_point.y),
-            trigger_area: Collider {
-                width: transition.trigger_area.width,
-                height: transition.trigger_area.height,
-                offset_x: transition.trigger_area.x,
-                offset_y: transition.trigger_area.y,
+        commands.spawn((
+            LevelTransitionTrigger {
+                to_level: transition.to_level.clone(),
+                spawn_point: Position::new(transition.spawn_point.x, transition.spawn_point.y),
             },
-        });
+            Trigger,
+            Position::new(transition.trigger_area.x, transition.trigger_area.y),
+            Collider::new(
+                transition.trigger_area.width,
+                transition.trigger_area.height,
+            ),
+            Transform::default(),
+        ));
     }
 
     // Spawn ability gates
@@ -170,35 +329,24 @@ pub fn spawn_level_entities(commands: &mut Commands, level: &LevelData) {
 /// Detect when player reaches level transition trigger
 fn detect_level_transitions(
     mut commands: Commands,
-    player_query: Query<(&Position, &Collider), With<Player>>,
+    mut trigger_events: EventReader<TriggerEntered>,
+    player_query: Query<(), With<Player>>,
     trigger_query: Query<&LevelTransitionTrigger>,
 ) {
-    for (player_pos, player_collider) in player_query.iter() {
-        for trigger in trigger_query.iter() {
-            // Check if player collides with trigger area
-            let player_left = player_pos.x + player_collider.offset_x;
-            let player_right = player_left + player_collider.width;
-            let player_top = player_pos.y + player_collider.offset_y;
-            let player_bottom = player_top + player_collider.height;
-
-            let trigger_left = trigger.trigger_area.offset_x;
-            let trigger_right = trigger_left + trigger.trigger_area.width;
-            let trigger_top = trigger.trigger_area.offset_y;
-            let trigger_bottom = trigger_top + trigger.trigger_area.height;
-
-            if player_right > trigger_left
-                && player_left < trigger_right
-                && player_bottom > trigger_top
-                && player_top < trigger_bottom
-            {
-                // Player entered transition trigger
-                commands.insert_resource(PendingTransition {
-                    to_level: trigger.to_level.clone(),
-                    spawn_point: trigger.spawn_point,
-                });
-                return;
-            }
+    for event in trigger_events.read() {
+        let Ok(trigger) = trigger_query.get(event.trigger) else {
+            continue;
+        };
+        if !player_query.contains(event.entity) {
+            continue;
         }
+
+        // Player entered transition trigger
+        commands.insert_resource(PendingTransition {
+            to_level: trigger.to_level.clone(),
+            spawn_point: trigger.spawn_point,
+        });
+        return;
     }
 }
 
@@ -208,11 +356,13 @@ fn process_pending_transition(
     mut commands: Commands,
     pending: Option<Res<PendingTransition>>,
     _current_level: Option<Res<CurrentLevel>>,
-    mut player_query: Query<&mut Position, With<Player>>,
+    mut player_query: Query<(Entity, &mut Position), With<Player>>,
     geometry_query: Query<Entity, With<LevelGeometry>>,
     trigger_query: Query<Entity, With<LevelTransitionTrigger>>,
     power_up_query: Query<Entity, With<PowerUp>>,
     swing_point_query: Query<Entity, With<SwingPoint>>,
+    volume_query: Query<Entity, With<ForceVolume>>,
+    plate_query: Query<Entity, With<PressurePlate>>,
 ) {
     if let Some(pending) = pending {
         // Unload current level entities
@@ -228,11 +378,18 @@ fn process_pending_transition(
         for entity in swing_point_query.iter() {
             commands.entity(entity).despawn();
         }
+        for entity in volume_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        for entity in plate_query.iter() {
+            commands.entity(entity).despawn();
+        }
 
         // Spawn player at new spawn point (do this before loading to preserve state)
-        for mut player_pos in player_query.iter_mut() {
+        for (player, mut player_pos) in player_query.iter_mut() {
             player_pos.x = pending.spawn_point.x;
             player_pos.y = pending.spawn_point.y;
+            commands.entity(player).insert(Teleported);
         }
 
         // Load new level
@@ -284,15 +441,9 @@ fn update_ability_gates(
 
                 if should_block {
                     // Add blocking geometry
-                    commands.entity(gate_entity).insert((
-                        LevelGeometry {
-                            x: gate.gate_area.offset_x,
-                            y: gate.gate_area.offset_y,
-                            width: gate.gate_area.width,
-                            height: gate.gate_area.height,
-                        },
-                        AbilityGateGeometry,
-                    ));
+                    commands
+                        .entity(gate_entity)
+                        .insert((gate_geometry(&gate.gate_area), AbilityGateGeometry));
                 } else {
                     // Remove blocking geometry
                     for (geo_entity, geo) in gate_geometry_query.iter() {
@@ -319,21 +470,28 @@ fn update_ability_gates(
 
                 if !has_geometry {
                     // Add blocking geometry
-                    commands.entity(gate_entity).insert((
-                        LevelGeometry {
-                            x: gate.gate_area.offset_x,
-                            y: gate.gate_area.offset_y,
-                            width: gate.gate_area.width,
-                            height: gate.gate_area.height,
-                        },
-                        AbilityGateGeometry,
-                    ));
+                    commands
+                        .entity(gate_entity)
+                        .insert((gate_geometry(&gate.gate_area), AbilityGateGeometry));
                 }
             }
         }
     }
 }
 
+/// Geometry filling a gate's area that only collides with the player
+fn gate_geometry(gate_area: &Collider) -> LevelGeometry {
+    LevelGeometry {
+        layers: CollisionLayers::new(CollisionLayers::DEFAULT, CollisionLayers::PLAYER),
+        ..LevelGeometry::new(
+            gate_area.offset_x,
+            gate_area.offset_y,
+            gate_area.width,
+            gate_area.height,
+        )
+    }
+}
+
 /// Marker component for ability gate geometry
 #[derive(Component)]
 struct AbilityGateGeometry;
@@ -367,8 +525,11 @@ impl std::error::Error for LevelLoadError {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::enums::Ability;
-    use crate::level::{GeometryData, PowerUpData, SpawnPoint, SwingPointData};
+    use crate::components::Velocity;
+    use crate::enums::{Ability, BoxCorner, PathMode, VolumeEffect};
+    use crate::level::{MovingPlatformData, PowerUpData, SpawnPoint, SwingPointData, WaypointData};
+    use crate::plugins::{PhysicsPlugin, RenderTransformPlugin};
+    use crate::test_util::run_tick;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -384,8 +545,14 @@ mod tests {
                 y: 0.0,
                 width: 1920.0,
                 height: 64.0,
+                ..Default::default()
+            }],
+            swing_points: vec![SwingPointData {
+                x: 500.0,
+                y: 800.0,
+                min_length: None,
+                max_length: None,
             }],
-            swing_points: vec![SwingPointData { x: 500.0, y: 800.0 }],
             checkpoints: vec![],
             power_ups: vec![PowerUpData {
                 ability_type: Ability::HighJump,
@@ -394,6 +561,10 @@ mod tests {
             }],
             transitions: vec![],
             ability_gates: vec![],
+            moving_platforms: vec![],
+            force_volumes: vec![],
+            pushables: vec![],
+            pressure_plates: vec![],
         }
     }
 
@@ -469,6 +640,151 @@ mod tests {
         assert!(matches!(result, Err(LevelLoadError::ValidationError(_))));
     }
 
+    #[test]
+    fn test_validate_level_data_slope_without_corner() {
+        let mut level = create_test_level();
+        level.geometry[0].geometry_type = "triangle".to_string();
+
+        let result = validate_level_data(&level);
+        assert!(matches!(result, Err(LevelLoadError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_geometry_from_data_shapes() {
+        let mut geo = GeometryData {
+            geometry_type: "platform".to_string(),
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0,
+            ..Default::default()
+        };
+        assert_eq!(geometry_from_data(&geo).shape, GeometryShape::Box);
+
+        geo.geometry_type = "triangle".to_string();
+        geo.corner = Some(BoxCorner::BottomLeft);
+        let triangle = geometry_from_data(&geo);
+        assert_eq!(
+            triangle.shape,
+            GeometryShape::Triangle(BoxCorner::BottomLeft)
+        );
+        assert_eq!(triangle.x, 10.0);
+        assert_eq!(triangle.height, 50.0);
+
+        geo.geometry_type = "ramp".to_string();
+        assert_eq!(
+            geometry_from_data(&geo).shape,
+            GeometryShape::Ramp(BoxCorner::BottomLeft)
+        );
+        assert!(!geometry_from_data(&geo).one_way);
+
+        geo.geometry_type = "oneway".to_string();
+        let platform = geometry_from_data(&geo);
+        assert!(platform.one_way);
+        assert_eq!(platform.shape, GeometryShape::Box);
+        assert_eq!(platform.layers, CollisionLayers::default());
+
+        let enemy_wall = CollisionLayers::new(CollisionLayers::DEFAULT, CollisionLayers::ENEMY);
+        geo.layers = Some(enemy_wall);
+        assert_eq!(geometry_from_data(&geo).layers, enemy_wall);
+    }
+
+    #[test]
+    fn test_validate_level_data_moving_platform_without_waypoints() {
+        let mut level = create_test_level();
+        level.moving_platforms.push(MovingPlatformData {
+            width: 96.0,
+            height: 16.0,
+            waypoints: vec![],
+            mode: PathMode::Linear,
+            speed: 100.0,
+            pause: 0.0,
+            one_way: false,
+            layers: None,
+        });
+
+        let result = validate_level_data(&level);
+        assert!(matches!(result, Err(LevelLoadError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_spawn_moving_platforms() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        let mut level = create_test_level();
+        level.moving_platforms.push(MovingPlatformData {
+            width: 96.0,
+            height: 16.0,
+            waypoints: vec![
+                WaypointData { x: 300.0, y: 200.0 },
+                WaypointData { x: 300.0, y: 400.0 },
+            ],
+            mode: PathMode::PingPong,
+            speed: 100.0,
+            pause: 1.0,
+            one_way: true,
+            layers: None,
+        });
+        app.add_systems(Startup, move |mut commands: Commands| {
+            spawn_level_entities(&mut commands, &level)
+        });
+        app.update();
+
+        let (geometry, platform) = app
+            .world
+            .query::<(&LevelGeometry, &MovingPlatform)>()
+            .single(&app.world);
+        assert_eq!((geometry.x, geometry.y), (300.0, 200.0));
+        assert_eq!(geometry.width, 96.0);
+        assert!(geometry.one_way);
+        assert_eq!(platform.waypoints.len(), 2);
+        assert_eq!(platform.mode, PathMode::PingPong);
+        assert_eq!(platform.pause, 1.0);
+    }
+
+    #[test]
+    fn test_spawned_level_entities_are_placed_for_rendering() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(RenderTransformPlugin);
+
+        let mut level = create_test_level();
+        level.geometry[0].x = 64.0;
+        level.geometry[0].y = 1016.0;
+        app.add_systems(Startup, move |mut commands: Commands| {
+            spawn_level_entities(&mut commands, &level)
+        });
+        app.update();
+
+        let geometry_transform = app
+            .world
+            .query_filtered::<&Transform, With<LevelGeometry>>()
+            .single(&app.world);
+        assert_eq!(
+            geometry_transform.translation,
+            Vec3::new(64.0, -1016.0, 0.0)
+        );
+
+        let power_up_transform = app
+            .world
+            .query_filtered::<&Transform, With<PowerUp>>()
+            .single(&app.world);
+        assert_eq!(
+            power_up_transform.translation,
+            Vec3::new(800.0, -200.0, 0.0)
+        );
+
+        let swing_point_transform = app
+            .world
+            .query_filtered::<&Transform, With<SwingPoint>>()
+            .single(&app.world);
+        assert_eq!(
+            swing_point_transform.translation,
+            Vec3::new(500.0, -800.0, 0.0)
+        );
+    }
+
     #[test]
     fn test_spawn_level_entities() {
         let mut app = App::new();
@@ -483,12 +799,17 @@ mod tests {
                 y: geo.y,
                 width: geo.width,
                 height: geo.height,
+                ..Default::default()
             });
         }
 
         for swing_point in &level.swing_points {
             app.world.spawn((
-                SwingPoint { range: 100.0 },
+                SwingPoint {
+                    range: 100.0,
+                    min_length: swing_point.min_length.unwrap_or(MIN_ROPE_LENGTH),
+                    max_length: swing_point.max_length.unwrap_or(MAX_ROPE_LENGTH),
+                },
                 Position::new(swing_point.x, swing_point.y),
             ));
         }
@@ -518,29 +839,29 @@ mod tests {
     #[test]
     fn test_level_transition_detection() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn player at transition trigger location
         app.world.spawn((
             Player,
             Position::new(1800.0, 100.0),
+            Velocity::default(),
             Collider::new(32.0, 64.0),
         ));
 
         // Spawn transition trigger
-        app.world.spawn(LevelTransitionTrigger {
-            to_level: "level_02".to_string(),
-            spawn_point: Position::new(100.0, 500.0),
-            trigger_area: Collider {
-                width: 64.0,
-                height: 200.0,
-                offset_x: 1800.0,
-                offset_y: 100.0,
+        app.world.spawn((
+            LevelTransitionTrigger {
+                to_level: "level_02".to_string(),
+                spawn_point: Position::new(100.0, 500.0),
             },
-        });
+            Trigger,
+            Position::new(1800.0, 100.0),
+            Collider::new(64.0, 200.0),
+        ));
 
-        // Run one update
-        app.update();
+        run_tick(&mut app);
 
         // Verify pending transition was created
         assert!(app.world.get_resource::<PendingTransition>().is_some());
@@ -549,29 +870,29 @@ mod tests {
     #[test]
     fn test_no_transition_when_far_away() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn player far from trigger
         app.world.spawn((
             Player,
             Position::new(100.0, 100.0),
+            Velocity::default(),
             Collider::new(32.0, 64.0),
         ));
 
         // Spawn transition trigger
-        app.world.spawn(LevelTransitionTrigger {
-            to_level: "level_02".to_string(),
-            spawn_point: Position::new(100.0, 500.0),
-            trigger_area: Collider {
-                width: 64.0,
-                height: 200.0,
-                offset_x: 1800.0,
-                offset_y: 100.0,
+        app.world.spawn((
+            LevelTransitionTrigger {
+                to_level: "level_02".to_string(),
+                spawn_point: Position::new(100.0, 500.0),
             },
-        });
+            Trigger,
+            Position::new(1800.0, 100.0),
+            Collider::new(64.0, 200.0),
+        ));
 
-        // Run one update
-        app.update();
+        run_tick(&mut app);
 
         // Verify no pending transition
         assert!(app.world.get_resource::<PendingTransition>().is_none());
@@ -580,7 +901,8 @@ mod tests {
     #[test]
     fn test_player_position_updated_on_transition() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn player
         let player = app
@@ -605,12 +927,16 @@ mod tests {
         let player_pos = app.world.get::<Position>(player).unwrap();
         assert_eq!(player_pos.x, 500.0);
         assert_eq!(player_pos.y, 600.0);
+
+        // The move is marked so rendering does not interpolate across the level
+        assert!(app.world.get::<Teleported>(player).is_some());
     }
 
     #[test]
     fn test_level_entities_despawned_on_transition() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn some level entities
         app.world.spawn(LevelGeometry {
@@ -618,6 +944,7 @@ mod tests {
             y: 0.0,
             width: 100.0,
             height: 32.0,
+            ..Default::default()
         });
 
         app.world.spawn((
@@ -627,6 +954,14 @@ mod tests {
             Position::new(100.0, 100.0),
         ));
 
+        app.world.spawn(ForceVolume::new(
+            0.0,
+            0.0,
+            100.0,
+            100.0,
+            VolumeEffect::Wind { x: 100.0, y: 0.0 },
+        ));
+
         // Verify entities exist
         let geo_count_before = app.world.query::<&LevelGeometry>().iter(&app.world).count();
         assert_eq!(geo_count_before, 1);
@@ -646,12 +981,16 @@ mod tests {
 
         let power_up_count_after = app.world.query::<&PowerUp>().iter(&app.world).count();
         assert_eq!(power_up_count_after, 0);
+
+        let volume_count_after = app.world.query::<&ForceVolume>().iter(&app.world).count();
+        assert_eq!(volume_count_after, 0);
     }
 
     #[test]
     fn test_ability_gate_blocks_without_ability() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn player without required ability
         app.world.spawn((
@@ -686,12 +1025,17 @@ mod tests {
         // Verify blocking geometry exists
         let geo_count = app.world.query::<&LevelGeometry>().iter(&app.world).count();
         assert_eq!(geo_count, 1);
+
+        // Verify it only blocks the player
+        let geometry = app.world.query::<&LevelGeometry>().single(&app.world);
+        assert_eq!(geometry.layers.mask, CollisionLayers::PLAYER);
     }
 
     #[test]
     fn test_ability_gate_opens_with_ability() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn player with required ability
         let mut abilities = AbilitySet::new();
@@ -730,7 +1074,8 @@ mod tests {
     #[test]
     fn test_ability_gate_blocks_different_ability() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins).add_plugins(LevelPlugin);
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PhysicsPlugin, LevelPlugin));
 
         // Spawn player with different ability
         let mut abilities = AbilitySet::new();
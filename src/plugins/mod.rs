@@ -1,17 +1,33 @@
 pub mod ability;
 pub mod animation;
+pub mod broadphase;
 pub mod camera;
 pub mod checkpoint;
+pub mod controls;
 pub mod level;
 pub mod physics;
+pub mod platform;
 pub mod player;
+pub mod pushable;
+pub mod query;
+pub mod render_transform;
+pub mod replay;
+pub mod schedule;
 pub mod swing;
+pub mod trigger;
+pub mod tuning;
+pub mod volume;
 
 pub use ability::AbilityPlugin;
 pub use animation::AnimationPlugin;
 pub use camera::CameraPlugin;
 pub use checkpoint::CheckpointPlugin;
+pub use controls::ControlsPlugin;
 pub use level::LevelPlugin;
 pub use physics::PhysicsPlugin;
 pub use player::PlayerPlugin;
+pub use render_transform::RenderTransformPlugin;
+pub use replay::ReplayPlugin;
+pub use schedule::GameSet;
 pub use swing::SwingPlugin;
+pub use tuning::TuningPlugin;
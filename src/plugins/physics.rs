@@ -1,15 +1,58 @@
 use crate::components::{
-    Collider, GroundedState, LevelGeometry, Position, SwingState, Velocity, WallClimbState,
+    Collider, CollisionLayers, ForceVolume, GeometryShape, GroundedState, LevelGeometry, Player,
+    Position, SurfaceMaterial, SwingState, Velocity, WallClimbState,
 };
+use crate::enums::BoxCorner;
+use crate::plugins::broadphase::{SpatialHash, update_spatial_hash};
+use crate::plugins::platform::{carry_riders, move_platforms};
+use crate::plugins::pushable::{push_bodies, sync_pushable_geometry, update_pressure_plates};
+use crate::plugins::schedule::{GameSet, configure_game_sets};
+use crate::plugins::trigger::{TriggerEntered, TriggerExited, detect_triggers};
+use crate::plugins::volume::{VolumeEffects, volume_effects_at};
+use crate::tuning::MovementTuning;
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 /// Physics constants
-pub const GRAVITY: f32 = 980.0; // pixels per second squared
 const FIXED_TIMESTEP: f32 = 1.0 / 60.0; // 60 FPS fixed timestep
-const GROUND_CHECK_EPSILON: f32 = 2.0; // Distance to check for ground contact
-const SLOPE_FRICTION: f32 = 0.3; // Friction coefficient for slopes
+pub const GROUND_CHECK_EPSILON: f32 = 2.0; // Distance to check for ground contact
 const MIN_SLOPE_ANGLE: f32 = 0.1; // Minimum angle (radians) to be considered a slope
 pub const WALL_CHECK_DISTANCE: f32 = 5.0; // Distance to check for adjacent walls
+pub const MAX_COLLISION_ITERATIONS: usize = 4; // Slide iterations per fixed step
+pub const COLLISION_EPSILON: f32 = 0.01; // Penetration tolerated as touching contact
+pub const GROUND_SNAP_DISTANCE: f32 = 8.0; // Distance grounded bodies are pulled down to stay on slopes
+pub const DEFAULT_SUBSTEPS: u32 = 2; // Movement sub-steps per fixed step
+pub const MAX_SPEED: f32 = 2400.0; // Fastest any body may move, in pixels per second
+pub const MIN_BOUNCE_SPEED: f32 = 30.0; // Slower bounces come to rest instead
+pub const MAX_DEPENETRATION_ITERATIONS: usize = 4; // Push-outs tried before searching for an escape
+
+/// Limits on how bodies are integrated; movement feel lives in MovementTuning
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct PhysicsSettings {
+    pub substeps: u32,  // Movement is split into this many sweeps per fixed step
+    pub max_speed: f32, // Overall speed limit in any direction
+}
+
+impl Default for PhysicsSettings {
+    fn default() -> Self {
+        Self {
+            substeps: DEFAULT_SUBSTEPS,
+            max_speed: MAX_SPEED,
+        }
+    }
+}
+
+impl PhysicsSettings {
+    /// Limit a velocity to the overall speed limit, keeping its direction
+    pub fn clamp(&self, velocity: &mut Velocity) {
+        let speed = Vec2::new(velocity.x, velocity.y).length();
+        if speed > self.max_speed {
+            let scale = self.max_speed / speed;
+            velocity.x *= scale;
+            velocity.y *= scale;
+        }
+    }
+}
 
 /// Collision result
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -18,47 +61,163 @@ pub enum CollisionResult {
     Collided(Vec2), // Contains collision normal
 }
 
+/// A single contact produced while resolving an entity's movement
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub entity: Entity,
+    pub normal: Vec2,
+    pub time: f32, // Fraction of the step's movement completed when the contact occurred
+}
+
+/// Every contact an entity touched during the last fixed step
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct CollisionContacts {
+    pub contacts: Vec<Contact>,
+}
+
+/// Sent the first fixed step a body with CollisionContacts touches another entity
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct CollisionStarted {
+    pub entity: Entity,
+    pub other: Entity,
+    pub normal: Vec2, // Normal of the first contact with `other`, pointing away from it
+}
+
+/// Sent the first fixed step a body with CollisionContacts no longer touches another entity
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollisionEnded {
+    pub entity: Entity,
+    pub other: Entity,
+}
+
+/// Sent every fixed step the player overlaps geometry with no way out, e.g. when a gate
+/// closes on them or a platform pins them against a wall
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlayerCrushed {
+    pub entity: Entity,
+}
+
+/// Makes a body fall through the one-way platforms at its feet
+/// Removed once the body's feet are below `from_y` or it lands on solid ground
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct DropThrough {
+    pub from_y: f32, // Feet height when the drop started
+}
+
+impl DropThrough {
+    /// Whether the body should pass through this geometry
+    pub fn ignores(&self, geometry: &LevelGeometry) -> bool {
+        geometry.one_way && geometry.y <= self.from_y + COLLISION_EPSILON
+    }
+}
+
 /// Plugin for collision detection, movement, and gravity
 pub struct PhysicsPlugin;
 
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(Time::<Fixed>::from_seconds(FIXED_TIMESTEP as f64));
+        app.insert_resource(Time::<Fixed>::from_seconds(FIXED_TIMESTEP as f64))
+            .init_resource::<SpatialHash>()
+            .init_resource::<PhysicsSettings>()
+            .init_resource::<MovementTuning>()
+            .add_event::<CollisionStarted>()
+            .add_event::<CollisionEnded>()
+            .add_event::<PlayerCrushed>()
+            .add_event::<TriggerEntered>()
+            .add_event::<TriggerExited>();
+        // Geometry spawned or despawned outside the fixed loop is picked up before Update
+        app.add_systems(PreUpdate, update_spatial_hash);
+        configure_game_sets(app);
         app.add_systems(
             FixedUpdate,
             (
-                apply_gravity,
-                resolve_collisions,
-                update_grounded_state,
-                apply_slope_physics,
-                detect_adjacent_walls,
-            )
-                .chain(),
+                (
+                    move_platforms,
+                    push_bodies,
+                    sync_pushable_geometry,
+                    update_spatial_hash,
+                    carry_riders,
+                    apply_gravity,
+                    depenetrate_bodies,
+                    resolve_collisions,
+                    sync_pushable_geometry,
+                )
+                    .chain()
+                    .in_set(GameSet::Physics),
+                (
+                    emit_collision_events,
+                    update_grounded_state,
+                    release_drop_through,
+                    apply_slope_physics,
+                    detect_adjacent_walls,
+                    update_pressure_plates,
+                    detect_triggers,
+                )
+                    .chain()
+                    .in_set(GameSet::PostPhysics),
+            ),
         );
     }
 }
 
-/// Apply gravity to airborne entities
+/// Apply gravity and the force volumes each body is in
+/// Grounded bodies are held up against anything pushing them into the ground
+#[allow(clippy::type_complexity)]
 fn apply_gravity(
     mut query: Query<(
         &mut Velocity,
         &GroundedState,
         &WallClimbState,
         Option<&SwingState>,
+        Option<&Position>,
+        Option<&Collider>,
     )>,
+    volume_query: Query<&ForceVolume>,
+    tuning: Res<MovementTuning>,
     time: Res<Time<Fixed>>,
 ) {
     let delta_time = time.delta_seconds();
 
-    for (mut velocity, grounded, wall_state, swing_state) in query.iter_mut() {
-        // Only apply gravity if not grounded AND not clinging to wall AND not swinging
-        if !grounded.is_grounded && !wall_state.is_clinging && swing_state.is_none() {
-            velocity.y += GRAVITY * delta_time;
+    for (mut velocity, grounded, wall_state, swing_state, position, collider) in query.iter_mut() {
+        // Clinging and swinging bodies are held by the wall or the rope
+        if wall_state.is_clinging || swing_state.is_some() {
+            continue;
         }
+
+        let effects = match (position, collider) {
+            (Some(position), Some(collider)) => {
+                volume_effects_at(position, collider, volume_query.iter())
+            }
+            _ => VolumeEffects::default(),
+        };
+
+        let mut acceleration = effects.gravity * tuning.gravity + effects.acceleration;
+        if grounded.is_grounded {
+            acceleration.y = acceleration.y.min(0.0);
+        }
+
+        let mut current = Vec2::new(velocity.x, velocity.y) + acceleration * delta_time;
+        current *= (1.0 - effects.drag * delta_time).max(0.0);
+
+        // Falling speeds up until terminal velocity, and swimming is capped at the swim speed
+        if let Some(down) = effects.gravity.try_normalize() {
+            let falling = current.dot(down);
+            if falling > tuning.max_fall_speed {
+                current -= down * (falling - tuning.max_fall_speed);
+            }
+        }
+        if let Some(swim_speed) = effects.swim_speed {
+            current = current.clamp_length_max(swim_speed);
+        }
+
+        velocity.x = current.x;
+        velocity.y = current.y;
     }
 }
 
 /// Swept AABB collision detection
+/// Sloped geometry adds its surface normal as a third separating axis, so the same
+/// sweep handles boxes, right triangles and ramps
 /// Returns (time_of_impact, collision_normal) if collision occurs
 pub fn swept_aabb_collision(
     pos: &Position,
@@ -66,6 +225,10 @@ pub fn swept_aabb_collision(
     geometry: &LevelGeometry,
     movement: Vec2,
 ) -> Option<(f32, Vec2)> {
+    if movement == Vec2::ZERO {
+        return None;
+    }
+
     // Calculate AABB bounds for the entity
     let entity_left = pos.x + collider.offset_x;
     let entity_right = entity_left + collider.width;
@@ -79,143 +242,708 @@ pub fn swept_aabb_collision(
     let geo_bottom = geometry.y + geometry.height;
 
     // Calculate entry and exit times for each axis
-    let entry_x: f32;
-    let exit_x: f32;
-
-    if movement.x > 0.0 {
-        entry_x = geo_left - entity_right;
-        exit_x = geo_right - entity_left;
-    } else if movement.x < 0.0 {
-        entry_x = geo_right - entity_left;
-        exit_x = geo_left - entity_right;
-    } else {
-        entry_x = f32::NEG_INFINITY;
-        exit_x = f32::INFINITY;
+    let (entry_time_x, exit_time_x) =
+        axis_sweep(entity_left, entity_right, geo_left, geo_right, movement.x)?;
+    let (entry_time_y, exit_time_y) =
+        axis_sweep(entity_top, entity_bottom, geo_top, geo_bottom, movement.y)?;
+
+    // Project the entity onto the slope normal and sweep along it as well
+    let slope = match slope_axis(geometry) {
+        Some((axis, slope_min, slope_max)) => {
+            let center = Vec2::new(entity_left + entity_right, entity_top + entity_bottom) / 2.0;
+            let half_extent =
+                (collider.width * axis.x.abs() + collider.height * axis.y.abs()) / 2.0;
+            let projected = center.dot(axis);
+            let speed = movement.dot(axis);
+            let (entry, exit) = axis_sweep(
+                projected - half_extent,
+                projected + half_extent,
+                slope_min,
+                slope_max,
+                speed,
+            )?;
+            Some((entry, exit, axis * speed.signum()))
+        }
+        None => None,
+    };
+
+    // Find the latest entry time and earliest exit time
+    let mut entry_time = entry_time_x.max(entry_time_y);
+    let mut exit_time = exit_time_x.min(exit_time_y);
+    if let Some((slope_entry, slope_exit, _)) = slope {
+        entry_time = entry_time.max(slope_entry);
+        exit_time = exit_time.min(slope_exit);
     }
 
-    let entry_y: f32;
-    let exit_y: f32;
+    // Check if collision occurs (overlap must be deeper than touching, and a body that
+    // starts overlapping on every axis is left alone)
+    let time_epsilon = COLLISION_EPSILON / movement.length();
+    if exit_time - entry_time <= time_epsilon || !(0.0..=1.0).contains(&entry_time) {
+        return None;
+    }
 
-    if movement.y > 0.0 {
-        entry_y = geo_top - entity_bottom;
-        exit_y = geo_bottom - entity_top;
-    } else if movement.y < 0.0 {
-        entry_y = geo_bottom - entity_top;
-        exit_y = geo_top - entity_bottom;
-    } else {
-        entry_y = f32::NEG_INFINITY;
-        exit_y = f32::INFINITY;
+    // Calculate collision normal
+    let normal = match slope {
+        // Prefer the slope surface when it is (nearly) the last axis to overlap
+        Some((slope_entry, _, direction)) if slope_entry + time_epsilon >= entry_time => -direction,
+        _ => {
+            if entry_time_x > entry_time_y {
+                if movement.x > 0.0 {
+                    Vec2::new(-1.0, 0.0) // Hit left side
+                } else {
+                    Vec2::new(1.0, 0.0) // Hit right side
+                }
+            } else if movement.y > 0.0 {
+                Vec2::new(0.0, -1.0) // Hit top side
+            } else {
+                Vec2::new(0.0, 1.0) // Hit bottom side
+            }
+        }
+    };
+
+    // One-way platforms only block bodies landing on them from above
+    if geometry.one_way && normal != Vec2::new(0.0, -1.0) {
+        return None;
+    }
+
+    Some((entry_time.max(0.0), normal))
+}
+
+/// Entry and exit times of the entity's interval sweeping past the geometry's interval
+/// along one axis. Returns None when the axis has no movement and they do not overlap.
+fn axis_sweep(
+    entity_min: f32,
+    entity_max: f32,
+    geo_min: f32,
+    geo_max: f32,
+    speed: f32,
+) -> Option<(f32, f32)> {
+    if speed == 0.0 {
+        // An axis without movement never changes its overlap, so it must already overlap
+        // (touching edges do not count, otherwise sliding snags on adjacent tiles)
+        if entity_max - geo_min <= COLLISION_EPSILON || geo_max - entity_min <= COLLISION_EPSILON {
+            return None;
+        }
+        return Some((f32::NEG_INFINITY, f32::INFINITY));
     }
 
-    // Calculate time of entry and exit
-    let entry_time_x = if movement.x != 0.0 {
-        entry_x / movement.x
+    // Penetration within COLLISION_EPSILON is treated as touching (entry at zero)
+    let (entry, exit) = if speed > 0.0 {
+        (touching_entry(geo_min - entity_max), geo_max - entity_min)
     } else {
-        f32::NEG_INFINITY
+        (-touching_entry(entity_min - geo_max), geo_min - entity_max)
     };
-    let exit_time_x = if movement.x != 0.0 {
-        exit_x / movement.x
+
+    Some((entry / speed, exit / speed))
+}
+
+/// Snap a slightly negative entry distance (tiny penetration) to zero
+fn touching_entry(distance: f32) -> f32 {
+    if distance < 0.0 && distance > -COLLISION_EPSILON {
+        0.0
     } else {
-        f32::INFINITY
+        distance
+    }
+}
+
+/// Outward unit normal of a slope's surface and the geometry's extent along it
+/// Returns None for boxes, which only need the x and y axes
+pub fn slope_axis(geometry: &LevelGeometry) -> Option<(Vec2, f32, f32)> {
+    let (corner, solid) = match geometry.shape {
+        GeometryShape::Box => return None,
+        GeometryShape::Triangle(corner) => (corner, true),
+        GeometryShape::Ramp(corner) => (corner, false),
     };
-    let entry_time_y = if movement.y != 0.0 {
-        entry_y / movement.y
-    } else {
-        f32::NEG_INFINITY
+
+    let (left, right) = (geometry.x, geometry.x + geometry.width);
+    let (top, bottom) = (geometry.y, geometry.y + geometry.height);
+    let (right_angle, surface_point) = match corner {
+        BoxCorner::TopLeft => (Vec2::new(left, top), Vec2::new(right, top)),
+        BoxCorner::TopRight => (Vec2::new(right, top), Vec2::new(left, top)),
+        BoxCorner::BottomLeft => (Vec2::new(left, bottom), Vec2::new(right, bottom)),
+        BoxCorner::BottomRight => (Vec2::new(right, bottom), Vec2::new(left, bottom)),
     };
-    let exit_time_y = if movement.y != 0.0 {
-        exit_y / movement.y
+
+    // The hypotenuse runs between the two corners adjacent to the right angle; its
+    // normal points away from the right angle
+    let (dx, dy) = (geometry.width, geometry.height);
+    let mut axis = Vec2::new(dy, dx).normalize_or_zero();
+    let toward_surface = Vec2::new(left + right, top + bottom) / 2.0 - right_angle;
+    axis.x = axis.x.copysign(toward_surface.x);
+    axis.y = axis.y.copysign(toward_surface.y);
+
+    let surface = surface_point.dot(axis);
+    if solid {
+        Some((axis, right_angle.dot(axis), surface))
     } else {
-        f32::INFINITY
-    };
+        Some((axis, surface, surface))
+    }
+}
 
-    // Find the latest entry time and earliest exit time
-    let entry_time = entry_time_x.max(entry_time_y);
-    let exit_time = exit_time_x.min(exit_time_y);
+/// Whether a contact normal is walkable ground (points mostly up)
+pub fn is_ground_normal(normal: Vec2) -> bool {
+    normal.y < -0.5
+}
 
-    // Check if collision occurs
-    if entry_time > exit_time || entry_time_x < 0.0 && entry_time_y < 0.0 || entry_time > 1.0 {
-        return None;
+/// Whether the ground directly below the body is a one-way platform it can drop through
+pub fn is_on_one_way_platform<'a, I>(position: &Position, collider: &Collider, geometry: I) -> bool
+where
+    I: IntoIterator<Item = (Entity, &'a LevelGeometry)>,
+{
+    let check_movement = Vec2::new(0.0, GROUND_CHECK_EPSILON);
+
+    // Solid ground at the same height wins over a one-way platform
+    let mut earliest_ground: Option<(f32, bool)> = None;
+    for (_, geometry) in geometry {
+        if let Some((time, normal)) =
+            swept_aabb_collision(position, collider, geometry, check_movement)
+            && is_ground_normal(normal)
+            && earliest_ground.is_none_or(|(earliest_time, one_way)| {
+                time < earliest_time || (time == earliest_time && one_way)
+            })
+        {
+            earliest_ground = Some((time, geometry.one_way));
+        }
     }
 
-    // Calculate collision normal
-    let normal = if entry_time_x > entry_time_y {
-        if movement.x > 0.0 {
-            Vec2::new(-1.0, 0.0) // Hit left side
-        } else {
-            Vec2::new(1.0, 0.0) // Hit right side
+    earliest_ground.is_some_and(|(_, one_way)| one_way)
+}
+
+/// Geometry the broadphase finds within `reach` of a body that is on layers the body
+/// collides with, leaving out platforms it is dropping through
+pub fn nearby_geometry<'a>(
+    spatial_hash: &SpatialHash,
+    geometry_query: &'a Query<(Entity, &LevelGeometry)>,
+    position: &Position,
+    collider: &Collider,
+    layers: Option<&CollisionLayers>,
+    reach: f32,
+    drop_through: Option<&DropThrough>,
+) -> Vec<(Entity, &'a LevelGeometry)> {
+    let layers = layers.copied().unwrap_or_default();
+    spatial_hash
+        .query_around(position, collider, reach)
+        .into_iter()
+        .filter_map(|entity| geometry_query.get(entity).ok())
+        .filter(|(_, geometry)| layers.interacts_with(&geometry.layers))
+        .filter(|(_, geometry)| !drop_through.is_some_and(|drop| drop.ignores(geometry)))
+        .collect()
+}
+
+/// Earliest geometry hit by sweeping the entity along the movement
+/// Returns (entity, time_of_impact, collision_normal)
+pub(crate) fn earliest_collision<'a, I>(
+    position: &Position,
+    collider: &Collider,
+    geometry: I,
+    movement: Vec2,
+) -> Option<(Entity, f32, Vec2)>
+where
+    I: IntoIterator<Item = (Entity, &'a LevelGeometry)>,
+{
+    let mut earliest_collision: Option<(Entity, f32, Vec2)> = None;
+    for (entity, geometry) in geometry {
+        if let Some((time, normal)) = swept_aabb_collision(position, collider, geometry, movement)
+            && earliest_collision.is_none_or(|(_, earliest_time, _)| time < earliest_time)
+        {
+            earliest_collision = Some((entity, time, normal));
         }
-    } else if movement.y > 0.0 {
-        Vec2::new(0.0, -1.0) // Hit top side
-    } else {
-        Vec2::new(0.0, 1.0) // Hit bottom side
-    };
+    }
+    earliest_collision
+}
 
-    Some((entry_time.max(0.0), normal))
+/// Move an entity by its velocity, sliding along every surface it hits
+/// Each iteration advances to the earliest contact, then removes the component of the
+/// remaining movement and velocity along the contact normal. Returns every contact.
+pub fn move_and_slide<'a, I>(
+    position: &mut Position,
+    velocity: &mut Velocity,
+    collider: &Collider,
+    geometry: I,
+    delta_time: f32,
+) -> Vec<Contact>
+where
+    I: IntoIterator<Item = (Entity, &'a LevelGeometry)> + Clone,
+{
+    let mut contacts = Vec::new();
+    let mut remaining = Vec2::new(velocity.x * delta_time, velocity.y * delta_time);
+    let step_length = remaining.length();
+    let mut travelled = 0.0;
+
+    for _ in 0..MAX_COLLISION_ITERATIONS {
+        if remaining == Vec2::ZERO {
+            break;
+        }
+
+        // Find earliest collision along the remaining movement
+        let Some((entity, time, normal)) =
+            earliest_collision(position, collider, geometry.clone(), remaining)
+        else {
+            // No collision, move the rest of the way
+            position.x += remaining.x;
+            position.y += remaining.y;
+            break;
+        };
+
+        // Move to collision point
+        position.x += remaining.x * time;
+        position.y += remaining.y * time;
+        travelled += remaining.length() * time;
+
+        contacts.push(Contact {
+            entity,
+            normal,
+            time: if step_length > 0.0 {
+                travelled / step_length
+            } else {
+                0.0
+            },
+        });
+
+        // Slide the rest of the movement along the surface
+        remaining *= 1.0 - time;
+        remaining -= normal * remaining.dot(normal);
+
+        // Project velocity along collision surface (remove component along normal)
+        let dot = velocity.x * normal.x + velocity.y * normal.y;
+        velocity.x -= dot * normal.x;
+        velocity.y -= dot * normal.y;
+    }
+
+    contacts
+}
+
+/// Move a body down onto walkable ground within GROUND_SNAP_DISTANCE, if any
+fn snap_to_ground<'a, I>(
+    position: &mut Position,
+    collider: &Collider,
+    geometry: I,
+) -> Option<Contact>
+where
+    I: IntoIterator<Item = (Entity, &'a LevelGeometry)>,
+{
+    let probe = Vec2::new(0.0, GROUND_SNAP_DISTANCE);
+    let (entity, time, normal) = earliest_collision(position, collider, geometry, probe)?;
+    if !is_ground_normal(normal) {
+        return None;
+    }
+
+    position.y += probe.y * time;
+    Some(Contact {
+        entity,
+        normal,
+        time: 1.0,
+    })
 }
 
 /// Resolve collisions with level geometry
+/// Grounded bodies that are not moving upward keep a horizontal velocity, which is turned
+/// into movement along the ground surface and snapped back down onto slopes
+#[allow(clippy::type_complexity)]
 fn resolve_collisions(
-    mut query: Query<(&mut Position, &mut Velocity, &Collider, Option<&SwingState>)>,
-    geometry_query: Query<&LevelGeometry>,
+    mut query: Query<(
+        Entity,
+        &mut Position,
+        &mut Velocity,
+        &Collider,
+        Option<&GroundedState>,
+        Option<&DropThrough>,
+        Option<&CollisionLayers>,
+        Option<&mut CollisionContacts>,
+    )>,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    material_query: Query<&SurfaceMaterial>,
+    spatial_hash: Res<SpatialHash>,
+    settings: Res<PhysicsSettings>,
     time: Res<Time<Fixed>>,
 ) {
     let delta_time = time.delta_seconds();
+    let substeps = settings.substeps.max(1);
+    let substep_time = delta_time / substeps as f32;
+
+    for (entity, mut position, mut velocity, collider, grounded, drop_through, layers, contacts) in
+        query.iter_mut()
+    {
+        settings.clamp(&mut velocity);
+
+        let ground = grounded.filter(|grounded| grounded.is_grounded && velocity.y >= 0.0);
+        let following_ground = ground.map(|grounded| grounded.ground_normal);
+        let conveyor_velocity =
+            ground.map_or(0.0, |grounded| grounded.ground_material.conveyor_velocity);
+
+        // Sliding never travels further than the step's movement and the conveyor's, plus a
+        // possible ground snap
+        let reach = (surface_velocity(&velocity, following_ground).length()
+            + conveyor_velocity.abs())
+            * delta_time
+            + GROUND_SNAP_DISTANCE;
+        let mut geometry = nearby_geometry(
+            &spatial_hash,
+            &geometry_query,
+            &position,
+            collider,
+            layers,
+            reach,
+            drop_through,
+        );
+        // Pushables carry their own geometry, which they never collide with
+        geometry.retain(|(other, _)| *other != entity);
+
+        let mut step_contacts = Vec::new();
+        for substep in 0..substeps {
+            let mut substep_contacts = move_body(
+                &mut position,
+                &mut velocity,
+                collider,
+                following_ground,
+                &geometry,
+                |entity| material_query.get(entity).copied().unwrap_or_default(),
+                substep_time,
+            );
+            if let Some(ground_normal) = following_ground
+                && conveyor_velocity != 0.0
+            {
+                substep_contacts.extend(carry_along_ground(
+                    &mut position,
+                    collider,
+                    ground_normal,
+                    conveyor_velocity,
+                    &geometry,
+                    substep_time,
+                ));
+            }
 
-    for (mut position, mut velocity, collider, swing_state) in query.iter_mut() {
-        // Skip collision resolution if swinging (swing physics handles position)
-        if swing_state.is_some() {
-            continue;
+            // Report contact times as fractions of the whole step
+            step_contacts.extend(substep_contacts.into_iter().map(|contact| Contact {
+                time: (substep as f32 + contact.time) / substeps as f32,
+                ..contact
+            }));
         }
 
-        let movement = Vec2::new(velocity.x * delta_time, velocity.y * delta_time);
+        if let Some(mut contacts) = contacts {
+            contacts.contacts = step_contacts;
+        }
+    }
+}
+
+/// Push bodies out of geometry they overlap before they move
+/// Geometry can appear on top of a body (a gate closing) or be moved into it (a platform), and
+/// sweeps cannot separate a body that starts inside. A body with no way out is left where it is,
+/// and the player is reported as crushed.
+#[allow(clippy::type_complexity)]
+fn depenetrate_bodies(
+    mut query: Query<
+        (
+            Entity,
+            &mut Position,
+            &Collider,
+            Option<&DropThrough>,
+            Option<&CollisionLayers>,
+            Has<Player>,
+        ),
+        With<Velocity>,
+    >,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    spatial_hash: Res<SpatialHash>,
+    mut crushed_events: EventWriter<PlayerCrushed>,
+) {
+    for (entity, mut position, collider, drop_through, layers, is_player) in query.iter_mut() {
+        // A body is pushed at most its own size; anything deeper has no way out
+        let reach = collider.width.max(collider.height);
+        let geometry = nearby_geometry(
+            &spatial_hash,
+            &geometry_query,
+            &position,
+            collider,
+            layers,
+            reach,
+            drop_through,
+        );
+
+        // Pushables carry their own geometry, which never traps them
+        let others = geometry
+            .iter()
+            .filter(|(other, _)| *other != entity)
+            .map(|(_, geometry)| *geometry);
+        match depenetrate(&position, collider, others, reach) {
+            Some(escaped) => *position = escaped,
+            None => {
+                if is_player {
+                    crushed_events.send(PlayerCrushed { entity });
+                }
+            }
+        }
+    }
+}
 
-        // Find earliest collision
-        let mut earliest_collision: Option<(f32, Vec2)> = None;
-        let mut earliest_time = 1.0;
+/// Find the nearest position within `max_distance` where the body overlaps none of the geometry
+/// Each overlap is first pushed out along its minimum translation vector, which handles
+/// corners and stacked overlaps. If that fails, every single push-out is tried from the
+/// starting position, shortest first. Returns None when the body cannot escape.
+pub fn depenetrate<'a, I>(
+    position: &Position,
+    collider: &Collider,
+    geometry: I,
+    max_distance: f32,
+) -> Option<Position>
+where
+    I: IntoIterator<Item = &'a LevelGeometry> + Clone,
+{
+    let escapes = |escaped: &Position| {
+        Vec2::new(escaped.x - position.x, escaped.y - position.y).length() <= max_distance
+            && geometry
+                .clone()
+                .into_iter()
+                .all(|geometry| separating_translations(escaped, collider, geometry).is_empty())
+    };
 
-        for geometry in geometry_query.iter() {
-            if let Some((time, normal)) =
-                swept_aabb_collision(&position, collider, geometry, movement)
-                && time < earliest_time
+    let mut pushed = *position;
+    for _ in 0..MAX_DEPENETRATION_ITERATIONS {
+        let mut moved = false;
+        for geometry in geometry.clone() {
+            let translations = separating_translations(&pushed, collider, geometry);
+            if let Some(translation) = translations
+                .into_iter()
+                .min_by(|a, b| a.length().total_cmp(&b.length()))
             {
-                earliest_time = time;
-                earliest_collision = Some((time, normal));
+                pushed.x += translation.x;
+                pushed.y += translation.y;
+                moved = true;
             }
         }
+        if !moved {
+            break;
+        }
+    }
+    if escapes(&pushed) {
+        return Some(pushed);
+    }
 
-        if let Some((time, normal)) = earliest_collision {
-            // Move to collision point
-            position.x += velocity.x * delta_time * time;
-            position.y += velocity.y * delta_time * time;
+    let mut candidates: Vec<Vec2> = geometry
+        .clone()
+        .into_iter()
+        .flat_map(|geometry| separating_translations(position, collider, geometry))
+        .collect();
+    candidates.sort_by(|a, b| a.length().total_cmp(&b.length()));
+    candidates.into_iter().find_map(|translation| {
+        let escaped = Position::new(position.x + translation.x, position.y + translation.y);
+        escapes(&escaped).then_some(escaped)
+    })
+}
 
-            // Project velocity along collision surface (remove component along normal)
-            let dot = velocity.x * normal.x + velocity.y * normal.y;
-            velocity.x -= dot * normal.x;
-            velocity.y -= dot * normal.y;
-        } else {
-            // No collision, move full distance
-            position.x += velocity.x * delta_time;
-            position.y += velocity.y * delta_time;
+/// Translations that move a body just out of solid geometry it overlaps, one per side of
+/// each separating axis. Empty when the body does not overlap it.
+/// One-way platforms and ramps never trap a body, so they are ignored.
+fn separating_translations(
+    position: &Position,
+    collider: &Collider,
+    geometry: &LevelGeometry,
+) -> Vec<Vec2> {
+    if geometry.one_way || matches!(geometry.shape, GeometryShape::Ramp(_)) {
+        return Vec::new();
+    }
+
+    let left = position.x + collider.offset_x;
+    let top = position.y + collider.offset_y;
+    let (right, bottom) = (left + collider.width, top + collider.height);
+
+    let mut axes = vec![
+        (
+            Vec2::X,
+            (left, right),
+            (geometry.x, geometry.x + geometry.width),
+        ),
+        (
+            Vec2::Y,
+            (top, bottom),
+            (geometry.y, geometry.y + geometry.height),
+        ),
+    ];
+    if let Some((axis, slope_min, slope_max)) = slope_axis(geometry) {
+        let center = Vec2::new(left + right, top + bottom) / 2.0;
+        let half_extent = (collider.width * axis.x.abs() + collider.height * axis.y.abs()) / 2.0;
+        let projected = center.dot(axis);
+        axes.push((
+            axis,
+            (projected - half_extent, projected + half_extent),
+            (slope_min, slope_max),
+        ));
+    }
+
+    let mut translations = Vec::new();
+    for (axis, (entity_min, entity_max), (geo_min, geo_max)) in axes {
+        // Any axis without a real overlap separates them
+        if entity_max.min(geo_max) - entity_min.max(geo_min) <= COLLISION_EPSILON {
+            return Vec::new();
+        }
+        translations.push(-axis * (entity_max - geo_min));
+        translations.push(axis * (geo_max - entity_min));
+    }
+    translations
+}
+
+/// Velocity along the ground surface for grounded bodies, or the body's own velocity
+fn surface_velocity(velocity: &Velocity, following_ground: Option<Vec2>) -> Vec2 {
+    match following_ground {
+        Some(ground_normal) => {
+            // Walk along the surface at the current horizontal speed
+            let tangent = Vec2::new(-ground_normal.y, ground_normal.x);
+            Vec2::new(velocity.x, velocity.x * tangent.y / tangent.x)
+        }
+        None => Vec2::new(velocity.x, velocity.y),
+    }
+}
+
+/// Move one body for one sub-step against the given geometry, returning its contacts
+fn move_body(
+    position: &mut Position,
+    velocity: &mut Velocity,
+    collider: &Collider,
+    following_ground: Option<Vec2>,
+    geometry: &[(Entity, &LevelGeometry)],
+    material_of: impl Fn(Entity) -> SurfaceMaterial,
+    delta_time: f32,
+) -> Vec<Contact> {
+    if following_ground.is_some() {
+        let surface = surface_velocity(velocity, following_ground);
+        let mut surface_velocity = Velocity::new(surface.x, surface.y);
+        let mut contacts = move_and_slide(
+            position,
+            &mut surface_velocity,
+            collider,
+            geometry.iter().copied(),
+            delta_time,
+        );
+        velocity.x = surface_velocity.x;
+        velocity.y = 0.0;
+
+        // Stay attached when walking down a slope or over the crest of a ramp
+        if !contacts
+            .iter()
+            .any(|contact| is_ground_normal(contact.normal))
+            && let Some(contact) = snap_to_ground(position, collider, geometry.iter().copied())
+        {
+            contacts.push(contact);
+        }
+        contacts
+    } else {
+        let incoming = Vec2::new(velocity.x, velocity.y);
+        let contacts = move_and_slide(
+            position,
+            velocity,
+            collider,
+            geometry.iter().copied(),
+            delta_time,
+        );
+
+        // Bouncy surfaces send back part of the speed the body hit them with
+        let mut bounced = false;
+        for contact in &contacts {
+            let bounce = -incoming.dot(contact.normal) * material_of(contact.entity).restitution;
+            let current = Vec2::new(velocity.x, velocity.y).dot(contact.normal);
+            if bounce > MIN_BOUNCE_SPEED && bounce > current {
+                velocity.x += contact.normal.x * (bounce - current);
+                velocity.y += contact.normal.y * (bounce - current);
+                bounced = true;
+            }
+        }
+
+        // Landing on walkable ground ends vertical motion so the next step follows it
+        if !bounced
+            && contacts
+                .iter()
+                .any(|contact| is_ground_normal(contact.normal))
+        {
+            velocity.y = 0.0;
+        }
+        contacts
+    }
+}
+
+/// Move a body resting on a conveyor along the ground surface at the conveyor's speed
+/// Like a moving platform carrying its riders, this moves the body without changing its velocity
+fn carry_along_ground(
+    position: &mut Position,
+    collider: &Collider,
+    ground_normal: Vec2,
+    conveyor_velocity: f32,
+    geometry: &[(Entity, &LevelGeometry)],
+    delta_time: f32,
+) -> Vec<Contact> {
+    let surface = surface_velocity(&Velocity::new(conveyor_velocity, 0.0), Some(ground_normal));
+    let mut carried = Velocity::new(surface.x, surface.y);
+    move_and_slide(
+        position,
+        &mut carried,
+        collider,
+        geometry.iter().copied(),
+        delta_time,
+    )
+}
+
+/// Compare each body's contacts with the previous step's and report the ones that began or ended
+fn emit_collision_events(
+    mut touching: Local<HashMap<Entity, Vec<Entity>>>,
+    query: Query<(Entity, &CollisionContacts)>,
+    mut started_events: EventWriter<CollisionStarted>,
+    mut ended_events: EventWriter<CollisionEnded>,
+) {
+    let mut current = HashMap::new();
+
+    for (entity, contacts) in query.iter() {
+        let previous = touching.get(&entity);
+        let mut others: Vec<Entity> = Vec::new();
+
+        for contact in &contacts.contacts {
+            if others.contains(&contact.entity) {
+                continue;
+            }
+            others.push(contact.entity);
+
+            if !previous.is_some_and(|previous| previous.contains(&contact.entity)) {
+                started_events.send(CollisionStarted {
+                    entity,
+                    other: contact.entity,
+                    normal: contact.normal,
+                });
+            }
+        }
+
+        if let Some(previous) = previous {
+            for &other in previous.iter().filter(|other| !others.contains(other)) {
+                ended_events.send(CollisionEnded { entity, other });
+            }
         }
+
+        current.insert(entity, others);
     }
+
+    // Bodies that lost their CollisionContacts or were despawned are forgotten without events
+    *touching = current;
 }
 
 /// Update grounded state based on ground contact
+#[allow(clippy::type_complexity)]
 fn update_grounded_state(
     mut query: Query<(
+        Entity,
         &Position,
         &Collider,
         &mut GroundedState,
         Option<&SwingState>,
+        Option<&DropThrough>,
+        Option<&CollisionLayers>,
     )>,
-    geometry_query: Query<&LevelGeometry>,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    material_query: Query<&SurfaceMaterial>,
+    spatial_hash: Res<SpatialHash>,
 ) {
-    for (position, collider, mut grounded_state, swing_state) in query.iter_mut() {
+    for (entity, position, collider, mut grounded_state, swing_state, drop_through, layers) in
+        query.iter_mut()
+    {
         // Not grounded while swinging
         if swing_state.is_some() {
-            grounded_state.is_grounded = false;
-            grounded_state.ground_normal = Vec2::ZERO;
+            *grounded_state = GroundedState::default();
             continue;
         }
 
@@ -224,27 +952,57 @@ fn update_grounded_state(
 
         let mut is_on_ground = false;
         let mut ground_normal = Vec2::ZERO;
-
-        for geometry in geometry_query.iter() {
-            if let Some((time, normal)) =
-                swept_aabb_collision(position, collider, geometry, check_movement)
-            {
-                // If collision happens very close and normal points up, we're on ground
-                if time < 1.0 && normal.y < -0.5 {
-                    is_on_ground = true;
-                    ground_normal = normal;
-                    break;
-                }
+        let mut ground_material = SurfaceMaterial::default();
+
+        // The closest surface below decides, so slopes report their own normal
+        // A pushable never stands on its own geometry
+        let geometry = nearby_geometry(
+            &spatial_hash,
+            &geometry_query,
+            position,
+            collider,
+            layers,
+            GROUND_CHECK_EPSILON,
+            drop_through,
+        )
+        .into_iter()
+        .filter(|(other, _)| *other != entity);
+        if let Some((ground, time, normal)) =
+            earliest_collision(position, collider, geometry, check_movement)
+        {
+            // If collision happens very close and normal points up, we're on ground
+            if time < 1.0 && is_ground_normal(normal) {
+                is_on_ground = true;
+                ground_normal = normal;
+                ground_material = material_query.get(ground).copied().unwrap_or_default();
             }
         }
 
         grounded_state.is_grounded = is_on_ground;
         grounded_state.ground_normal = ground_normal;
+        grounded_state.ground_material = ground_material;
+    }
+}
+
+/// Stop dropping through one-way platforms once clear of them or resting on solid ground
+fn release_drop_through(
+    mut commands: Commands,
+    query: Query<(Entity, &Position, &Collider, &GroundedState, &DropThrough)>,
+) {
+    for (entity, position, collider, grounded, drop_through) in query.iter() {
+        let feet = position.y + collider.offset_y + collider.height;
+        if grounded.is_grounded || feet > drop_through.from_y + COLLISION_EPSILON {
+            commands.entity(entity).remove::<DropThrough>();
+        }
     }
 }
 
 /// Apply slope physics (friction and sliding)
-fn apply_slope_physics(mut query: Query<(&mut Velocity, &GroundedState)>, time: Res<Time<Fixed>>) {
+fn apply_slope_physics(
+    mut query: Query<(&mut Velocity, &GroundedState)>,
+    tuning: Res<MovementTuning>,
+    time: Res<Time<Fixed>>,
+) {
     let delta_time = time.delta_seconds();
 
     for (mut velocity, grounded_state) in query.iter_mut() {
@@ -265,45 +1023,68 @@ fn apply_slope_physics(mut query: Query<(&mut Velocity, &GroundedState)>, time:
         }
 
         // Calculate sliding force along slope: F = g * sin(θ)
-        let sliding_acceleration = GRAVITY * slope_angle.sin();
+        let sliding_acceleration = tuning.gravity * slope_angle.sin();
 
         // Apply sliding force in the direction of the slope
         // Slope direction is perpendicular to normal
         let slope_direction = Vec2::new(-normal.y, normal.x);
 
+        // Grounded bodies keep a horizontal velocity that resolve_collisions carries along
+        // the surface, so only the horizontal part of the slope forces is applied
+
         // Add sliding velocity
         velocity.x += sliding_acceleration * slope_direction.x * delta_time;
-        velocity.y += sliding_acceleration * slope_direction.y * delta_time;
 
-        // Apply friction to oppose motion along slope
-        let velocity_along_slope = velocity.x * slope_direction.x + velocity.y * slope_direction.y;
-        let friction_force = -velocity_along_slope * SLOPE_FRICTION;
+        // Apply friction to oppose motion along slope, scaled by the surface's grip
+        let velocity_along_slope = velocity.x * slope_direction.x;
+        let friction_force =
+            -velocity_along_slope * tuning.slope_friction * grounded_state.ground_material.friction;
 
         velocity.x += friction_force * slope_direction.x * delta_time;
-        velocity.y += friction_force * slope_direction.y * delta_time;
     }
 }
 
 /// Detect adjacent walls and update WallClimbState
 fn detect_adjacent_walls(
-    mut query: Query<(&Position, &Collider, &mut WallClimbState)>,
-    geometry_query: Query<&LevelGeometry>,
+    mut query: Query<(
+        Entity,
+        &Position,
+        &Collider,
+        &mut WallClimbState,
+        Option<&CollisionLayers>,
+    )>,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    spatial_hash: Res<SpatialHash>,
 ) {
-    for (position, collider, mut wall_state) in query.iter_mut() {
+    for (entity, position, collider, mut wall_state, layers) in query.iter_mut() {
         let mut wall_detected = false;
         let mut detected_wall_normal = Vec2::ZERO;
+        // A pushable's own geometry is never a wall next to it
+        let geometry: Vec<_> = nearby_geometry(
+            &spatial_hash,
+            &geometry_query,
+            position,
+            collider,
+            layers,
+            WALL_CHECK_DISTANCE,
+            None,
+        )
+        .into_iter()
+        .filter(|(other, _)| *other != entity)
+        .collect();
 
         // Check for walls on left and right sides
         for direction in [
             Vec2::new(-WALL_CHECK_DISTANCE, 0.0),
             Vec2::new(WALL_CHECK_DISTANCE, 0.0),
         ] {
-            for geometry in geometry_query.iter() {
+            for (_, geometry) in &geometry {
                 if let Some((time, normal)) =
                     swept_aabb_collision(position, collider, geometry, direction)
                 {
                     // Wall detected if collision happens very close and normal is horizontal
-                    if time < 1.0 && normal.x.abs() > 0.5 {
+                    // (walkable slopes are steep enough to pass the x test, so exclude them)
+                    if time < 1.0 && normal.x.abs() > 0.5 && normal.y.abs() < 0.5 {
                         wall_detected = true;
                         detected_wall_normal = normal;
                         break;
@@ -327,6 +1108,12 @@ fn detect_adjacent_walls(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::VolumeEffect;
+    use crate::test_util::run_fixed_step;
+
+    fn tuning() -> MovementTuning {
+        MovementTuning::default()
+    }
 
     #[test]
     fn test_gravity_applied_when_airborne() {
@@ -334,14 +1121,15 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
 
         // Simulate one frame of gravity
         if !grounded.is_grounded {
-            velocity.y += GRAVITY * FIXED_TIMESTEP;
+            velocity.y += tuning().gravity * FIXED_TIMESTEP;
         }
 
-        let expected_velocity = GRAVITY * FIXED_TIMESTEP;
+        let expected_velocity = tuning().gravity * FIXED_TIMESTEP;
         assert!(
             (velocity.y - expected_velocity).abs() < 0.01,
             "Expected velocity.y to be ~{}, got {}",
@@ -356,11 +1144,12 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: true,
             ground_normal: Vec2::new(0.0, -1.0),
+            ..Default::default()
         };
 
         // Simulate one frame - gravity should NOT be applied
         if !grounded.is_grounded {
-            velocity.y += GRAVITY * FIXED_TIMESTEP;
+            velocity.y += tuning().gravity * FIXED_TIMESTEP;
         }
 
         assert_eq!(velocity.y, 0.0);
@@ -401,13 +1190,14 @@ mod tests {
             let grounded = GroundedState {
                 is_grounded: false,
                 ground_normal: Vec2::ZERO,
+                ..Default::default()
             };
 
             // Simulate 10 frames
             for _ in 0..10 {
                 // Apply gravity
                 if !grounded.is_grounded {
-                    velocity.y += GRAVITY * FIXED_TIMESTEP;
+                    velocity.y += tuning().gravity * FIXED_TIMESTEP;
                 }
 
                 // Integrate velocity
@@ -434,16 +1224,17 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
 
         // Simulate 5 frames
         for _ in 0..5 {
             if !grounded.is_grounded {
-                velocity.y += GRAVITY * FIXED_TIMESTEP;
+                velocity.y += tuning().gravity * FIXED_TIMESTEP;
             }
         }
 
-        let expected_velocity = GRAVITY * FIXED_TIMESTEP * 5.0;
+        let expected_velocity = tuning().gravity * FIXED_TIMESTEP * 5.0;
         assert!(
             (velocity.y - expected_velocity).abs() < 0.01,
             "Expected velocity.y to be ~{}, got {}",
@@ -485,6 +1276,7 @@ mod tests {
             y: 0.0,
             width: 100.0,
             height: 32.0,
+            ..Default::default()
         };
 
         // Moving right towards the geometry
@@ -512,6 +1304,7 @@ mod tests {
             y: 100.0,
             width: 50.0,
             height: 50.0,
+            ..Default::default()
         };
 
         // Moving away from geometry
@@ -530,6 +1323,7 @@ mod tests {
             y: 0.0,
             width: 100.0,
             height: 32.0,
+            ..Default::default()
         };
 
         // Moving down towards ground
@@ -575,6 +1369,7 @@ mod tests {
             y: 0.0,
             width: 5.0, // Very thin wall
             height: 100.0,
+            ..Default::default()
         };
 
         // High velocity movement that would tunnel without swept collision
@@ -596,6 +1391,7 @@ mod tests {
             y: 0.0,
             width: 200.0,
             height: 32.0,
+            ..Default::default()
         };
 
         // Check slightly below entity
@@ -619,6 +1415,7 @@ mod tests {
             y: 0.0,
             width: 10.0,
             height: 100.0,
+            ..Default::default()
         };
 
         let delta_time = FIXED_TIMESTEP;
@@ -668,11 +1465,11 @@ mod tests {
     fn test_slope_sliding_acceleration() {
         // Test that sliding acceleration equals g * sin(θ)
         let slope_angle = std::f32::consts::PI / 6.0; // 30 degrees
-        let expected_acceleration = GRAVITY * slope_angle.sin();
+        let expected_acceleration = tuning().gravity * slope_angle.sin();
 
         // For 30 degrees, sin(30°) = 0.5
         assert!(
-            (expected_acceleration - GRAVITY * 0.5).abs() < 1.0,
+            (expected_acceleration - tuning().gravity * 0.5).abs() < 1.0,
             "Sliding acceleration should be g * sin(θ)"
         );
     }
@@ -689,11 +1486,12 @@ mod tests {
         let _grounded_state = GroundedState {
             is_grounded: true,
             ground_normal: normal,
+            ..Default::default()
         };
 
         // Calculate expected sliding
         let calculated_angle = normal.x.atan2(-normal.y);
-        let sliding_acceleration = GRAVITY * calculated_angle.sin();
+        let sliding_acceleration = tuning().gravity * calculated_angle.sin();
         let slope_direction = Vec2::new(-normal.y, normal.x);
 
         // Apply for one frame
@@ -715,7 +1513,7 @@ mod tests {
         let slope_direction = Vec2::new(-normal.y, normal.x);
 
         let velocity_along_slope = velocity.x * slope_direction.x + velocity.y * slope_direction.y;
-        let friction_force = -velocity_along_slope * SLOPE_FRICTION;
+        let friction_force = -velocity_along_slope * tuning().slope_friction;
 
         let initial_velocity = velocity.x;
         velocity.x += friction_force * slope_direction.x * FIXED_TIMESTEP;
@@ -733,6 +1531,7 @@ mod tests {
         let _grounded_state = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::new(0.5, -0.866), // 30-degree slope
+            ..Default::default()
         };
 
         // Slope physics should not apply when airborne
@@ -749,6 +1548,7 @@ mod tests {
         let grounded_state = GroundedState {
             is_grounded: true,
             ground_normal: Vec2::new(0.0, -1.0), // Flat ground
+            ..Default::default()
         };
 
         let normal = grounded_state.ground_normal;
@@ -770,6 +1570,7 @@ mod tests {
             y: 0.0,
             width: 32.0,
             height: 200.0,
+            ..Default::default()
         };
 
         // Check for wall on left side
@@ -796,6 +1597,7 @@ mod tests {
             y: 0.0,
             width: 32.0,
             height: 200.0,
+            ..Default::default()
         };
 
         // Check for wall on right side
@@ -821,6 +1623,7 @@ mod tests {
             y: 0.0,
             width: 32.0,
             height: 200.0,
+            ..Default::default()
         };
 
         // Check for wall - should be too far
@@ -843,6 +1646,7 @@ mod tests {
             y: 0.0,
             width: 32.0,
             height: 200.0,
+            ..Default::default()
         };
 
         // Check for wall on left side
@@ -864,6 +1668,7 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
         let wall_state = WallClimbState {
             is_clinging: true,
@@ -872,7 +1677,7 @@ mod tests {
 
         // Simulate one frame of gravity with wall-cling
         if !grounded.is_grounded && !wall_state.is_clinging {
-            velocity.y += GRAVITY * FIXED_TIMESTEP;
+            velocity.y += tuning().gravity * FIXED_TIMESTEP;
         }
 
         assert_eq!(
@@ -887,20 +1692,17 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
         let wall_state = WallClimbState {
             is_clinging: false,
             wall_normal: Vec2::ZERO,
         };
-        let swing_state = Some(SwingState {
-            anchor_point: Vec2::new(100.0, 200.0),
-            rope_length: 50.0,
-            angular_velocity: 0.0,
-        });
+        let swing_state = Some(SwingState::new(Vec2::new(100.0, 200.0), 50.0, 0.0));
 
         // Simulate one frame of gravity with swing
         if !grounded.is_grounded && !wall_state.is_clinging && swing_state.is_none() {
-            velocity.y += GRAVITY * FIXED_TIMESTEP;
+            velocity.y += tuning().gravity * FIXED_TIMESTEP;
         }
 
         assert_eq!(
@@ -908,4 +1710,976 @@ mod tests {
             "Gravity should not be applied during swing"
         );
     }
+
+    fn geometry_with_entities(boxes: &[LevelGeometry]) -> Vec<(Entity, LevelGeometry)> {
+        boxes
+            .iter()
+            .enumerate()
+            .map(|(i, geo)| (Entity::from_raw(i as u32), geo.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_swept_aabb_ignores_geometry_outside_stationary_axis() {
+        // Falling straight down next to (not above) a platform must not hit it
+        let position = Position::new(0.0, 0.0);
+        let collider = Collider::new(32.0, 32.0);
+        let platform = LevelGeometry {
+            x: 100.0,
+            y: 40.0,
+            width: 100.0,
+            height: 32.0,
+            ..Default::default()
+        };
+
+        let result = swept_aabb_collision(&position, &collider, &platform, Vec2::new(0.0, 20.0));
+        assert!(result.is_none(), "Should not hit geometry beside the path");
+    }
+
+    #[test]
+    fn test_move_and_slide_runs_across_adjacent_floor_tiles() {
+        // Player resting on a floor built from three adjacent tiles
+        let mut position = Position::new(10.0, 0.0);
+        let mut velocity = Velocity::new(600.0, 60.0);
+        let collider = Collider::new(32.0, 32.0);
+        let geometry = geometry_with_entities(&[
+            LevelGeometry {
+                x: 0.0,
+                y: 32.0,
+                width: 32.0,
+                height: 32.0,
+                ..Default::default()
+            },
+            LevelGeometry {
+                x: 32.0,
+                y: 32.0,
+                width: 32.0,
+                height: 32.0,
+                ..Default::default()
+            },
+            LevelGeometry {
+                x: 64.0,
+                y: 32.0,
+                width: 32.0,
+                height: 32.0,
+                ..Default::default()
+            },
+        ]);
+
+        let contacts = move_and_slide(
+            &mut position,
+            &mut velocity,
+            &collider,
+            geometry.iter().map(|(entity, geo)| (*entity, geo)),
+            FIXED_TIMESTEP,
+        );
+
+        assert!(
+            (position.x - 20.0).abs() < 0.01,
+            "Should keep full horizontal movement, got {}",
+            position.x
+        );
+        assert_eq!(position.y, 0.0, "Should stay on top of the floor");
+        assert_eq!(velocity.x, 600.0, "Horizontal velocity should be kept");
+        assert_eq!(velocity.y, 0.0, "Vertical velocity should be removed");
+        assert!(
+            contacts
+                .iter()
+                .all(|contact| contact.normal == Vec2::new(0.0, -1.0)),
+            "Should only touch the floor, not the tile seams: {:?}",
+            contacts
+        );
+    }
+
+    #[test]
+    fn test_move_and_slide_landing_in_wall_corner() {
+        // Falling diagonally into the corner between a floor and a wall on the right
+        let mut position = Position::new(0.0, 0.0);
+        let mut velocity = Velocity::new(600.0, 600.0);
+        let collider = Collider::new(32.0, 32.0);
+        let floor = LevelGeometry {
+            x: -100.0,
+            y: 37.0,
+            width: 300.0,
+            height: 32.0,
+            ..Default::default()
+        };
+        let wall = LevelGeometry {
+            x: 36.0,
+            y: -100.0,
+            width: 32.0,
+            height: 137.0,
+            ..Default::default()
+        };
+        let geometry = geometry_with_entities(&[floor, wall]);
+
+        let contacts = move_and_slide(
+            &mut position,
+            &mut velocity,
+            &collider,
+            geometry.iter().map(|(entity, geo)| (*entity, geo)),
+            FIXED_TIMESTEP,
+        );
+
+        assert!((position.x - 4.0).abs() < 0.01, "Should stop at the wall");
+        assert!((position.y - 5.0).abs() < 0.01, "Should land on the floor");
+        assert_eq!(velocity.x, 0.0);
+        assert_eq!(velocity.y, 0.0);
+        assert_eq!(contacts.len(), 2, "Should report both contacts");
+        assert_eq!(contacts[0].normal, Vec2::new(-1.0, 0.0));
+        assert_eq!(contacts[0].entity, Entity::from_raw(1));
+        assert_eq!(contacts[1].normal, Vec2::new(0.0, -1.0));
+        assert_eq!(contacts[1].entity, Entity::from_raw(0));
+        assert!(contacts[0].time <= contacts[1].time);
+    }
+
+    #[test]
+    fn test_move_and_slide_without_geometry_moves_full_distance() {
+        let mut position = Position::new(0.0, 0.0);
+        let mut velocity = Velocity::new(60.0, -120.0);
+        let collider = Collider::new(32.0, 32.0);
+
+        let contacts = move_and_slide(
+            &mut position,
+            &mut velocity,
+            &collider,
+            std::iter::empty(),
+            FIXED_TIMESTEP,
+        );
+
+        assert!(contacts.is_empty());
+        assert!((position.x - 1.0).abs() < 0.001);
+        assert!((position.y + 2.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_resolve_collisions_reports_contacts() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+
+        let player = app
+            .world
+            .spawn((
+                Position::new(0.0, 0.0),
+                Velocity::new(0.0, 600.0),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState::default(),
+                CollisionContacts::default(),
+            ))
+            .id();
+        let floor = app
+            .world
+            .spawn(LevelGeometry {
+                x: -100.0,
+                y: 37.0,
+                width: 300.0,
+                height: 32.0,
+                ..Default::default()
+            })
+            .id();
+
+        // Falling 10 pixels per step lands on the floor in a single step
+        run_fixed_step(&mut app);
+
+        let contacts = app.world.get::<CollisionContacts>(player).unwrap();
+        assert!(
+            contacts
+                .contacts
+                .iter()
+                .any(|contact| contact.entity == floor),
+            "Landing should report the floor contact"
+        );
+        assert!(app.world.get::<GroundedState>(player).unwrap().is_grounded);
+    }
+
+    #[test]
+    fn test_collision_events_on_landing_and_leaving() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+
+        let player = app
+            .world
+            .spawn((
+                Position::new(0.0, 0.0),
+                Velocity::new(0.0, 600.0),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState::default(),
+                CollisionContacts::default(),
+            ))
+            .id();
+        let floor = app
+            .world
+            .spawn(LevelGeometry::new(-100.0, 37.0, 300.0, 32.0))
+            .id();
+
+        run_fixed_step(&mut app);
+        let started: Vec<_> = app
+            .world
+            .resource_mut::<Events<CollisionStarted>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            started,
+            vec![CollisionStarted {
+                entity: player,
+                other: floor,
+                normal: Vec2::new(0.0, -1.0),
+            }]
+        );
+
+        // Resting on the floor keeps the contact without repeating the event
+        run_fixed_step(&mut app);
+        assert!(
+            app.world
+                .resource_mut::<Events<CollisionStarted>>()
+                .drain()
+                .next()
+                .is_none()
+        );
+        assert!(
+            app.world
+                .resource_mut::<Events<CollisionEnded>>()
+                .drain()
+                .next()
+                .is_none()
+        );
+
+        app.world.get_mut::<Velocity>(player).unwrap().y = -600.0;
+        run_fixed_step(&mut app);
+        let ended: Vec<_> = app
+            .world
+            .resource_mut::<Events<CollisionEnded>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            ended,
+            vec![CollisionEnded {
+                entity: player,
+                other: floor,
+            }]
+        );
+    }
+
+    fn spawn_body(app: &mut App, x: f32, y: f32) -> Entity {
+        app.world
+            .spawn((
+                Position::new(x, y),
+                Velocity::default(),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState::default(),
+            ))
+            .id()
+    }
+
+    /// Floor on the left, a slope rising to the right, and a plateau on the right
+    fn spawn_slope_course(app: &mut App) {
+        app.world
+            .spawn(LevelGeometry::new(-300.0, 100.0, 300.0, 32.0));
+        app.world.spawn(LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::BottomRight),
+            ..LevelGeometry::new(0.0, 50.0, 100.0, 50.0)
+        });
+        app.world
+            .spawn(LevelGeometry::new(100.0, 50.0, 300.0, 82.0));
+    }
+
+    #[test]
+    fn test_slope_axis_points_away_from_right_angle() {
+        let rising_right = LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::BottomRight),
+            ..LevelGeometry::new(0.0, 0.0, 100.0, 100.0)
+        };
+        let (axis, min, max) = slope_axis(&rising_right).unwrap();
+        assert!(axis.x < 0.0 && axis.y < 0.0, "Normal should point up-left");
+        assert!((axis.length() - 1.0).abs() < 0.001);
+        assert!(min < max, "Triangle should have thickness along its normal");
+
+        let ceiling = LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::TopLeft),
+            ..LevelGeometry::new(0.0, 0.0, 100.0, 100.0)
+        };
+        let (axis, _, _) = slope_axis(&ceiling).unwrap();
+        assert!(
+            axis.x > 0.0 && axis.y > 0.0,
+            "Normal should point down-right"
+        );
+
+        let ramp = LevelGeometry {
+            shape: GeometryShape::Ramp(BoxCorner::BottomLeft),
+            ..LevelGeometry::new(0.0, 0.0, 100.0, 100.0)
+        };
+        let (_, min, max) = slope_axis(&ramp).unwrap();
+        assert_eq!(min, max, "Ramp should have no thickness");
+
+        assert!(slope_axis(&LevelGeometry::new(0.0, 0.0, 10.0, 10.0)).is_none());
+    }
+
+    #[test]
+    fn test_swept_collision_against_slope_returns_slope_normal() {
+        // 45-degree slope rising to the right, falling onto its middle
+        let slope = LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::BottomRight),
+            ..LevelGeometry::new(0.0, 0.0, 100.0, 100.0)
+        };
+        let collider = Collider::new(10.0, 10.0);
+        let position = Position::new(40.0, 20.0);
+
+        let (time, normal) =
+            swept_aabb_collision(&position, &collider, &slope, Vec2::new(0.0, 50.0)).unwrap();
+
+        // Bottom-right corner (50, 30) meets the surface y = 100 - x at y = 50
+        assert!((time - 0.4).abs() < 0.001, "Got time {}", time);
+        let expected = Vec2::new(-1.0, -1.0).normalize();
+        assert!(
+            (normal - expected).length() < 0.001,
+            "Got normal {:?}",
+            normal
+        );
+        assert!(is_ground_normal(normal));
+    }
+
+    #[test]
+    fn test_swept_collision_misses_empty_half_of_triangle() {
+        // Falling into the bounding box above the hypotenuse but not reaching it
+        let slope = LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::BottomRight),
+            ..LevelGeometry::new(0.0, 0.0, 100.0, 100.0)
+        };
+        let collider = Collider::new(10.0, 10.0);
+        let position = Position::new(5.0, 0.0);
+
+        let result = swept_aabb_collision(&position, &collider, &slope, Vec2::new(0.0, 20.0));
+        assert!(result.is_none(), "Box overlap alone should not collide");
+    }
+
+    #[test]
+    fn test_walk_up_slope_stays_grounded() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        spawn_slope_course(&mut app);
+        let body = spawn_body(&mut app, -100.0, 68.0);
+
+        run_fixed_step(&mut app);
+        for step in 0..90 {
+            app.world.get_mut::<Velocity>(body).unwrap().x = 200.0;
+            run_fixed_step(&mut app);
+
+            let grounded = app.world.get::<GroundedState>(body).unwrap();
+            assert!(
+                grounded.is_grounded,
+                "Should stay grounded at step {}",
+                step
+            );
+            let position = app.world.get::<Position>(body).unwrap();
+            assert!(
+                position.y >= 18.0 - 0.1,
+                "Should not launch off the crest, y = {}",
+                position.y
+            );
+        }
+
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!(
+            position.x > 100.0,
+            "Should reach the plateau, x = {}",
+            position.x
+        );
+        assert!(
+            (position.y - 18.0).abs() < 0.1,
+            "Should stand on the plateau"
+        );
+    }
+
+    #[test]
+    fn test_walk_down_slope_stays_grounded() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        spawn_slope_course(&mut app);
+        let body = spawn_body(&mut app, 150.0, 18.0);
+
+        run_fixed_step(&mut app);
+        let mut touched_slope = false;
+        for step in 0..90 {
+            app.world.get_mut::<Velocity>(body).unwrap().x = -200.0;
+            run_fixed_step(&mut app);
+
+            let grounded = app.world.get::<GroundedState>(body).unwrap();
+            assert!(
+                grounded.is_grounded,
+                "Should stay grounded at step {}",
+                step
+            );
+            touched_slope |= grounded.ground_normal.x != 0.0;
+        }
+
+        assert!(
+            touched_slope,
+            "Should report a non-axis ground normal on the slope"
+        );
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!(
+            position.x < -32.0,
+            "Should reach the floor, x = {}",
+            position.x
+        );
+        assert!((position.y - 68.0).abs() < 0.1, "Should stand on the floor");
+    }
+
+    #[test]
+    fn test_land_on_ramp_segment() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world.spawn(LevelGeometry {
+            shape: GeometryShape::Ramp(BoxCorner::BottomLeft),
+            ..LevelGeometry::new(0.0, 100.0, 200.0, 100.0)
+        });
+        let body = spawn_body(&mut app, 84.0, 50.0);
+
+        for _ in 0..60 {
+            run_fixed_step(&mut app);
+            if app.world.get::<GroundedState>(body).unwrap().is_grounded {
+                break;
+            }
+        }
+
+        let grounded = app.world.get::<GroundedState>(body).unwrap();
+        assert!(grounded.is_grounded, "Should land on the ramp");
+        assert!(
+            grounded.ground_normal.x > 0.0 && grounded.ground_normal.y < 0.0,
+            "Ramp descending to the right should have an up-right normal, got {:?}",
+            grounded.ground_normal
+        );
+    }
+
+    #[test]
+    fn test_slope_physics_slides_downhill() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        spawn_slope_course(&mut app);
+        // Standing still in the middle of the slope
+        let body = spawn_body(&mut app, 34.0, 0.0);
+
+        for _ in 0..30 {
+            run_fixed_step(&mut app);
+        }
+
+        let grounded = app.world.get::<GroundedState>(body).unwrap();
+        assert!(grounded.is_grounded);
+        let velocity = app.world.get::<Velocity>(body).unwrap();
+        assert!(
+            velocity.x < 0.0,
+            "Should slide down the slope (left), got {}",
+            velocity.x
+        );
+    }
+
+    fn one_way_platform(x: f32, y: f32, width: f32) -> LevelGeometry {
+        LevelGeometry {
+            one_way: true,
+            ..LevelGeometry::new(x, y, width, 16.0)
+        }
+    }
+
+    #[test]
+    fn test_one_way_platform_blocks_only_from_above() {
+        let collider = Collider::new(32.0, 32.0);
+        let platform = one_way_platform(0.0, 100.0, 100.0);
+
+        // Falling onto it from above
+        let above = Position::new(10.0, 60.0);
+        let result = swept_aabb_collision(&above, &collider, &platform, Vec2::new(0.0, 20.0));
+        assert_eq!(result, Some((0.4, Vec2::new(0.0, -1.0))));
+
+        // Jumping into it from below
+        let below = Position::new(10.0, 130.0);
+        let result = swept_aabb_collision(&below, &collider, &platform, Vec2::new(0.0, -20.0));
+        assert!(result.is_none(), "Should pass through from below");
+
+        // Running into its side
+        let beside = Position::new(-40.0, 95.0);
+        let result = swept_aabb_collision(&beside, &collider, &platform, Vec2::new(20.0, 0.0));
+        assert!(result.is_none(), "Should pass through from the side");
+    }
+
+    #[test]
+    fn test_jump_up_through_one_way_platform() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world.spawn(one_way_platform(-100.0, 0.0, 300.0));
+        let body = spawn_body(&mut app, 0.0, 40.0);
+        app.world.get_mut::<Velocity>(body).unwrap().y = -600.0;
+
+        for _ in 0..120 {
+            run_fixed_step(&mut app);
+        }
+
+        let grounded = app.world.get::<GroundedState>(body).unwrap();
+        assert!(grounded.is_grounded, "Should land on top of the platform");
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!(
+            (position.y + 32.0).abs() < 0.1,
+            "Should stand on the platform, y = {}",
+            position.y
+        );
+    }
+
+    #[test]
+    fn test_drop_through_one_way_platform() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world.spawn(one_way_platform(-100.0, 0.0, 300.0));
+        app.world
+            .spawn(LevelGeometry::new(-100.0, 100.0, 300.0, 32.0));
+        let body = spawn_body(&mut app, 0.0, -32.0);
+
+        run_fixed_step(&mut app);
+        assert!(app.world.get::<GroundedState>(body).unwrap().is_grounded);
+
+        app.world
+            .entity_mut(body)
+            .insert(DropThrough { from_y: 0.0 });
+        for _ in 0..60 {
+            run_fixed_step(&mut app);
+        }
+
+        assert!(
+            app.world.get::<DropThrough>(body).is_none(),
+            "Drop-through should be released once clear"
+        );
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!(
+            (position.y - 68.0).abs() < 0.1,
+            "Should fall through onto the floor, y = {}",
+            position.y
+        );
+    }
+
+    #[test]
+    fn test_is_on_one_way_platform() {
+        let collider = Collider::new(32.0, 32.0);
+        let position = Position::new(0.0, -32.0);
+        let geometry = geometry_with_entities(&[one_way_platform(-100.0, 0.0, 300.0)]);
+        assert!(is_on_one_way_platform(
+            &position,
+            &collider,
+            geometry.iter().map(|(entity, geo)| (*entity, geo))
+        ));
+
+        let geometry = geometry_with_entities(&[
+            one_way_platform(-100.0, 0.0, 300.0),
+            LevelGeometry::new(-100.0, 0.0, 300.0, 32.0),
+        ]);
+        assert!(
+            !is_on_one_way_platform(
+                &position,
+                &collider,
+                geometry.iter().map(|(entity, geo)| (*entity, geo))
+            ),
+            "Solid ground at the same height should prevent dropping"
+        );
+    }
+
+    #[test]
+    fn test_collision_layers_filter_geometry() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        // A barrier only the player collides with, above a floor everything collides with
+        app.world.spawn(LevelGeometry {
+            layers: CollisionLayers::new(CollisionLayers::DEFAULT, CollisionLayers::PLAYER),
+            ..LevelGeometry::new(-100.0, 50.0, 300.0, 16.0)
+        });
+        app.world
+            .spawn(LevelGeometry::new(-100.0, 200.0, 300.0, 32.0));
+
+        let player = spawn_body(&mut app, 0.0, 0.0);
+        app.world
+            .entity_mut(player)
+            .insert(CollisionLayers::player());
+        let other = spawn_body(&mut app, 100.0, 0.0);
+
+        for _ in 0..120 {
+            run_fixed_step(&mut app);
+        }
+
+        let player_y = app.world.get::<Position>(player).unwrap().y;
+        let other_y = app.world.get::<Position>(other).unwrap().y;
+        assert!(
+            (player_y - 18.0).abs() < 0.1,
+            "Player should stand on the barrier, y = {}",
+            player_y
+        );
+        assert!(
+            (other_y - 168.0).abs() < 0.1,
+            "Other bodies should fall to the floor, y = {}",
+            other_y
+        );
+    }
+
+    #[test]
+    fn test_velocity_clamped_to_max_speed() {
+        let settings = PhysicsSettings::default();
+
+        let mut launched = Velocity::new(-8000.0, -6000.0);
+        settings.clamp(&mut launched);
+        assert!((Vec2::new(launched.x, launched.y).length() - MAX_SPEED).abs() < 0.01);
+        assert!(launched.x < 0.0 && launched.y < 0.0, "Direction is kept");
+    }
+
+    #[test]
+    fn test_gravity_stops_at_terminal_velocity() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.insert_resource(MovementTuning {
+            max_fall_speed: 500.0,
+            ..Default::default()
+        });
+        let body = spawn_body(&mut app, 0.0, 0.0);
+        app.world.get_mut::<Velocity>(body).unwrap().y = 495.0;
+
+        run_fixed_step(&mut app);
+        run_fixed_step(&mut app);
+
+        assert_eq!(app.world.get::<Velocity>(body).unwrap().y, 500.0);
+    }
+
+    #[test]
+    fn test_landing_on_trampoline_bounces() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+
+        app.world.spawn((
+            LevelGeometry::new(-100.0, 37.0, 300.0, 32.0),
+            SurfaceMaterial::trampoline(),
+        ));
+        let body = spawn_body(&mut app, 0.0, 0.0);
+        app.world.get_mut::<Velocity>(body).unwrap().y = 600.0;
+
+        run_fixed_step(&mut app);
+
+        let velocity = app.world.get::<Velocity>(body).unwrap().y;
+        let expected = -(600.0 + tuning().gravity * FIXED_TIMESTEP) * 0.9;
+        assert!(
+            (velocity - expected).abs() < 0.1,
+            "Expected a bounce of {expected}, got {velocity}"
+        );
+        assert!(app.world.get::<Position>(body).unwrap().y <= 5.0);
+    }
+
+    #[test]
+    fn test_soft_bounce_comes_to_rest() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+
+        app.world.spawn((
+            LevelGeometry::new(-100.0, 37.0, 300.0, 32.0),
+            SurfaceMaterial::new(1.0, 0.5, 0.0),
+        ));
+        let body = spawn_body(&mut app, 0.0, 4.5);
+        app.world.get_mut::<Velocity>(body).unwrap().y = 40.0;
+
+        run_fixed_step(&mut app);
+
+        assert_eq!(app.world.get::<Velocity>(body).unwrap().y, 0.0);
+    }
+
+    #[test]
+    fn test_conveyor_carries_resting_body() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+
+        app.world.spawn((
+            LevelGeometry::new(-100.0, 32.0, 1000.0, 32.0),
+            SurfaceMaterial::conveyor(120.0),
+        ));
+        let body = spawn_body(&mut app, 0.0, 0.0);
+
+        // The first step lands the body and records what it stands on
+        run_fixed_step(&mut app);
+        assert_eq!(
+            app.world
+                .get::<GroundedState>(body)
+                .unwrap()
+                .ground_material,
+            SurfaceMaterial::conveyor(120.0)
+        );
+
+        let start = app.world.get::<Position>(body).unwrap().x;
+        for _ in 0..30 {
+            run_fixed_step(&mut app);
+        }
+
+        let travelled = app.world.get::<Position>(body).unwrap().x - start;
+        assert!(
+            (travelled - 60.0).abs() < 0.1,
+            "Expected to ride 60 pixels, got {travelled}"
+        );
+        assert_eq!(app.world.get::<Velocity>(body).unwrap().x, 0.0);
+        assert_eq!(app.world.get::<Position>(body).unwrap().y, 0.0);
+    }
+
+    fn volume_app(effect: VolumeEffect) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world
+            .spawn(ForceVolume::new(-500.0, -500.0, 1000.0, 1000.0, effect));
+        app
+    }
+
+    #[test]
+    fn test_wind_pushes_airborne_body() {
+        let mut app = volume_app(VolumeEffect::Wind { x: 300.0, y: 0.0 });
+        let body = spawn_body(&mut app, 0.0, 0.0);
+
+        run_fixed_step(&mut app);
+
+        let velocity = app.world.get::<Velocity>(body).unwrap();
+        assert!((velocity.x - 300.0 * FIXED_TIMESTEP).abs() < 0.001);
+        assert!((velocity.y - tuning().gravity * FIXED_TIMESTEP).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_gravity_volume_scales_and_turns_gravity() {
+        let mut app = volume_app(VolumeEffect::Gravity { x: 0.0, y: -0.5 });
+        let body = spawn_body(&mut app, 0.0, 0.0);
+
+        run_fixed_step(&mut app);
+
+        // Half strength, pulling up
+        let velocity = app.world.get::<Velocity>(body).unwrap().y;
+        assert!((velocity + 0.5 * tuning().gravity * FIXED_TIMESTEP).abs() < 0.001);
+        assert!(app.world.get::<Position>(body).unwrap().y < 0.0);
+    }
+
+    #[test]
+    fn test_water_floats_body_up_at_swim_speed() {
+        let mut app = volume_app(VolumeEffect::Water {
+            buoyancy: 3000.0,
+            drag: 0.5,
+            swim_speed: 100.0,
+        });
+        let body = spawn_body(&mut app, 0.0, 0.0);
+        app.world.get_mut::<Velocity>(body).unwrap().y = 400.0;
+
+        run_fixed_step(&mut app);
+        assert!(app.world.get::<Velocity>(body).unwrap().y <= 100.0);
+
+        for _ in 0..30 {
+            run_fixed_step(&mut app);
+        }
+        let velocity = app.world.get::<Velocity>(body).unwrap().y;
+        assert!(
+            (-100.01..0.0).contains(&velocity),
+            "Expected to rise at most at swim speed, got {velocity}"
+        );
+    }
+
+    #[test]
+    fn test_weak_buoyancy_keeps_body_on_the_ground() {
+        let mut app = volume_app(VolumeEffect::Water {
+            buoyancy: 500.0,
+            drag: 1.0,
+            swim_speed: 100.0,
+        });
+        app.world
+            .spawn(LevelGeometry::new(-100.0, 32.0, 300.0, 32.0));
+        let body = spawn_body(&mut app, 0.0, 0.0);
+
+        for _ in 0..10 {
+            run_fixed_step(&mut app);
+        }
+
+        assert_eq!(app.world.get::<Position>(body).unwrap().y, 0.0);
+        assert!(app.world.get::<GroundedState>(body).unwrap().is_grounded);
+    }
+
+    #[test]
+    fn test_depenetrate_uses_shortest_push() {
+        let collider = Collider::new(32.0, 32.0);
+        let wall = LevelGeometry::new(20.0, -50.0, 100.0, 200.0);
+
+        let escaped = depenetrate(&Position::new(0.0, 0.0), &collider, [&wall], 32.0).unwrap();
+        assert_eq!(escaped, Position::new(-12.0, 0.0));
+
+        // Touching is not overlapping
+        let touching = Position::new(-12.0, 0.0);
+        assert_eq!(
+            depenetrate(&touching, &collider, [&wall], 32.0),
+            Some(touching)
+        );
+    }
+
+    #[test]
+    fn test_depenetrate_out_of_corner() {
+        let collider = Collider::new(32.0, 32.0);
+        let floor = LevelGeometry::new(-100.0, 30.0, 300.0, 32.0);
+        let wall = LevelGeometry::new(28.0, -100.0, 32.0, 200.0);
+
+        let escaped =
+            depenetrate(&Position::new(0.0, 0.0), &collider, [&floor, &wall], 32.0).unwrap();
+        assert_eq!(escaped, Position::new(-4.0, -2.0));
+    }
+
+    #[test]
+    fn test_depenetrate_searches_past_blocked_push() {
+        // The shortest push out of the gate is into the wall, so the body goes the other way
+        let collider = Collider::new(32.0, 32.0);
+        let gate = LevelGeometry::new(-10.0, -100.0, 44.0, 200.0);
+        let wall = LevelGeometry::new(36.0, -100.0, 100.0, 200.0);
+
+        let escaped =
+            depenetrate(&Position::new(0.0, 0.0), &collider, [&gate, &wall], 64.0).unwrap();
+        assert_eq!(escaped, Position::new(-42.0, 0.0));
+
+        // Out of reach is no escape
+        assert_eq!(
+            depenetrate(&Position::new(0.0, 0.0), &collider, [&gate, &wall], 32.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_geometry_appearing_on_body_pushes_it_out() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world
+            .spawn(LevelGeometry::new(-100.0, 32.0, 300.0, 32.0));
+        let body = spawn_body(&mut app, 0.0, 0.0);
+
+        // A gate closes over the right half of the body
+        app.world
+            .spawn(LevelGeometry::new(20.0, -100.0, 32.0, 132.0));
+        run_fixed_step(&mut app);
+
+        let position = app.world.get::<Position>(body).unwrap();
+        assert_eq!(position.x, -12.0);
+        assert_eq!(position.y, 0.0);
+    }
+
+    #[test]
+    fn test_player_crushed_when_no_escape() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        let player = spawn_body(&mut app, 0.0, 0.0);
+        app.world.entity_mut(player).insert(Player);
+        let other = spawn_body(&mut app, 0.0, 0.0);
+
+        // Walls closer together than the bodies are wide
+        app.world
+            .spawn(LevelGeometry::new(-100.0, -100.0, 106.0, 200.0));
+        app.world
+            .spawn(LevelGeometry::new(26.0, -100.0, 100.0, 200.0));
+        run_fixed_step(&mut app);
+
+        let crushed: Vec<_> = app
+            .world
+            .resource_mut::<Events<PlayerCrushed>>()
+            .drain()
+            .collect();
+        assert_eq!(crushed, vec![PlayerCrushed { entity: player }]);
+        assert_eq!(app.world.get::<Position>(other).unwrap().x, 0.0);
+    }
+
+    #[test]
+    fn test_substeps_report_contact_times_across_the_step() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.insert_resource(PhysicsSettings {
+            substeps: 4,
+            ..Default::default()
+        });
+
+        // 20 pixels of movement per step in 5 pixel sub-steps, landing 13 pixels down
+        let body = app
+            .world
+            .spawn((
+                Position::new(0.0, 0.0),
+                Velocity::new(0.0, 1200.0),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState::default(),
+                CollisionContacts::default(),
+            ))
+            .id();
+        app.world
+            .spawn(LevelGeometry::new(-100.0, 45.0, 300.0, 32.0));
+
+        run_fixed_step(&mut app);
+
+        assert!((app.world.get::<Position>(body).unwrap().y - 13.0).abs() < 0.01);
+        let contacts = &app.world.get::<CollisionContacts>(body).unwrap().contacts;
+        assert_eq!(contacts.len(), 1);
+        assert!(
+            (contacts[0].time - 0.65).abs() < 0.01,
+            "Contact time should span the whole step, got {}",
+            contacts[0].time
+        );
+    }
+
+    /// A body in a closed box whose walls, floor and ceiling are 4 pixels thick
+    fn spawn_thin_cage(app: &mut App) {
+        const THICKNESS: f32 = 4.0;
+        const INNER: f32 = 200.0;
+        app.world.spawn(LevelGeometry::new(
+            -THICKNESS,
+            -THICKNESS,
+            INNER + 2.0 * THICKNESS,
+            THICKNESS,
+        ));
+        app.world.spawn(LevelGeometry::new(
+            -THICKNESS,
+            INNER,
+            INNER + 2.0 * THICKNESS,
+            THICKNESS,
+        ));
+        app.world
+            .spawn(LevelGeometry::new(-THICKNESS, 0.0, THICKNESS, INNER));
+        app.world
+            .spawn(LevelGeometry::new(INNER, 0.0, THICKNESS, INNER));
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::prelude::ProptestConfig::with_cases(64))]
+
+        /// Bodies stay inside 4 pixel thick geometry at and well beyond the speed limit
+        #[test]
+        fn test_no_tunneling_through_thin_geometry(
+            angle in 0.0f32..std::f32::consts::TAU,
+            speed in 0.0f32..(MAX_SPEED * 4.0),
+            substeps in 1u32..4,
+        ) {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+            app.insert_resource(PhysicsSettings {
+                substeps,
+                ..Default::default()
+            });
+            spawn_thin_cage(&mut app);
+            let body = app
+                .world
+                .spawn((
+                    Position::new(84.0, 84.0),
+                    Velocity::new(angle.cos() * speed, angle.sin() * speed),
+                    Collider::new(32.0, 32.0),
+                    GroundedState::default(),
+                    WallClimbState::default(),
+                ))
+                .id();
+
+            for _ in 0..30 {
+                run_fixed_step(&mut app);
+
+                let position = app.world.get::<Position>(body).unwrap();
+                proptest::prop_assert!(
+                    (-0.01..=168.01).contains(&position.x)
+                        && (-0.01..=168.01).contains(&position.y),
+                    "Escaped the cage at ({}, {})",
+                    position.x,
+                    position.y
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,441 @@
+use crate::components::{
+    Collider, CollisionLayers, GroundedState, LevelGeometry, Position, SwingState, Velocity,
+    WallClimbState,
+};
+use crate::enums::PathMode;
+use crate::plugins::broadphase::SpatialHash;
+use crate::plugins::physics::{
+    GROUND_CHECK_EPSILON, WALL_CHECK_DISTANCE, is_ground_normal, move_and_slide, nearby_geometry,
+    swept_aabb_collision,
+};
+use bevy::prelude::*;
+
+/// Kinematic platform that moves its LevelGeometry along a waypoint path
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct MovingPlatform {
+    pub waypoints: Vec<Vec2>, // Top-left corner positions, visited in order
+    pub mode: PathMode,
+    pub speed: f32,           // Pixels per second
+    pub pause: f32,           // Seconds to wait at each waypoint
+    pub target: usize,        // Index of the waypoint being moved towards
+    pub reversing: bool,      // Ping-pong platforms travelling back along the path
+    pub pause_remaining: f32, // Seconds left to wait before moving on
+    pub delta: Vec2,          // Movement during the last fixed step
+}
+
+impl MovingPlatform {
+    /// Create a platform that starts at the first waypoint
+    pub fn new(waypoints: Vec<Vec2>, mode: PathMode, speed: f32, pause: f32) -> Self {
+        Self {
+            target: if waypoints.len() > 1 { 1 } else { 0 },
+            waypoints,
+            mode,
+            speed,
+            pause,
+            reversing: false,
+            pause_remaining: 0.0,
+            delta: Vec2::ZERO,
+        }
+    }
+
+    /// Follow the path from `from` for `delta_time` seconds, returning the new position
+    pub fn advance(&mut self, from: Vec2, delta_time: f32) -> Vec2 {
+        let mut position = from;
+        let mut time_left = delta_time;
+
+        if self.speed <= 0.0 {
+            return position;
+        }
+
+        // Each pass either waits or reaches a waypoint, so this bound only matters
+        // for paths of coincident waypoints with no pause
+        for _ in 0..(self.waypoints.len() + 1) * 2 {
+            if time_left <= 0.0 {
+                break;
+            }
+
+            if self.pause_remaining > 0.0 {
+                let waited = self.pause_remaining.min(time_left);
+                self.pause_remaining -= waited;
+                time_left -= waited;
+                continue;
+            }
+
+            let Some(&target) = self.waypoints.get(self.target) else {
+                break;
+            };
+            let to_target = target - position;
+            let distance = to_target.length();
+            let reach = self.speed * time_left;
+
+            if distance > reach {
+                position += to_target / distance * reach;
+                break;
+            }
+
+            position = target;
+            time_left -= distance / self.speed;
+
+            if !self.next_target() {
+                break;
+            }
+            self.pause_remaining = self.pause;
+        }
+
+        position
+    }
+
+    /// Pick the next waypoint, returning false once a linear path is finished
+    fn next_target(&mut self) -> bool {
+        let last = self.waypoints.len().saturating_sub(1);
+        if last == 0 {
+            return false;
+        }
+
+        match self.mode {
+            PathMode::Linear => {
+                if self.target >= last {
+                    return false;
+                }
+                self.target += 1;
+            }
+            PathMode::Loop => {
+                self.target = (self.target + 1) % self.waypoints.len();
+            }
+            PathMode::PingPong => {
+                if self.reversing && self.target == 0 {
+                    self.reversing = false;
+                } else if !self.reversing && self.target == last {
+                    self.reversing = true;
+                }
+
+                if self.reversing {
+                    self.target -= 1;
+                } else {
+                    self.target += 1;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Move platforms along their paths, recording each step's delta
+pub(crate) fn move_platforms(
+    mut query: Query<(&mut MovingPlatform, &mut LevelGeometry)>,
+    time: Res<Time<Fixed>>,
+) {
+    let delta_time = time.delta_seconds();
+
+    for (mut platform, mut geometry) in query.iter_mut() {
+        let from = Vec2::new(geometry.x, geometry.y);
+        let to = platform.advance(from, delta_time);
+
+        platform.delta = to - from;
+        geometry.x = to.x;
+        geometry.y = to.y;
+    }
+}
+
+/// Carry bodies standing on or clinging to a moving platform by the platform's delta
+/// Runs before gravity and collision, so a body jumping off still leaves from where
+/// the platform took it
+#[allow(clippy::type_complexity)]
+pub(crate) fn carry_riders(
+    mut riders: Query<
+        (
+            &mut Position,
+            &Collider,
+            &GroundedState,
+            Option<&WallClimbState>,
+            Option<&CollisionLayers>,
+        ),
+        Without<SwingState>,
+    >,
+    platforms: Query<(Entity, &MovingPlatform, &LevelGeometry)>,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    spatial_hash: Res<SpatialHash>,
+) {
+    for (mut position, collider, grounded, wall_state, layers) in riders.iter_mut() {
+        let body_layers = layers.copied().unwrap_or_default();
+        let ridden = platforms.iter().find(|(_, platform, geometry)| {
+            // Test against where the platform was when the rider last touched it
+            let previous = LevelGeometry {
+                x: geometry.x - platform.delta.x,
+                y: geometry.y - platform.delta.y,
+                ..(*geometry).clone()
+            };
+            platform.delta != Vec2::ZERO
+                && body_layers.interacts_with(&geometry.layers)
+                && is_riding(&position, collider, grounded, wall_state, &previous)
+        });
+        let Some((platform_entity, platform, _)) = ridden else {
+            continue;
+        };
+
+        // Slide against the rest of the level so riders aren't pushed into walls
+        let mut others = nearby_geometry(
+            &spatial_hash,
+            &geometry_query,
+            &position,
+            collider,
+            layers,
+            platform.delta.length(),
+            None,
+        );
+        others.retain(|(entity, _)| *entity != platform_entity);
+        let mut carry = Velocity::new(platform.delta.x, platform.delta.y);
+        move_and_slide(
+            &mut position,
+            &mut carry,
+            collider,
+            others.iter().copied(),
+            1.0,
+        );
+    }
+}
+
+/// Whether a body is standing on or clinging to the given geometry
+fn is_riding(
+    position: &Position,
+    collider: &Collider,
+    grounded: &GroundedState,
+    wall_state: Option<&WallClimbState>,
+    geometry: &LevelGeometry,
+) -> bool {
+    let touches = |movement: Vec2| {
+        swept_aabb_collision(position, collider, geometry, movement)
+            .filter(|(time, _)| *time < 1.0)
+            .map(|(_, normal)| normal)
+    };
+
+    if grounded.is_grounded
+        && touches(Vec2::new(0.0, GROUND_CHECK_EPSILON)).is_some_and(is_ground_normal)
+    {
+        return true;
+    }
+
+    wall_state.is_some_and(|wall| {
+        wall.is_clinging
+            && touches(-wall.wall_normal * WALL_CHECK_DISTANCE)
+                .is_some_and(|n| n == wall.wall_normal)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::PhysicsPlugin;
+    use crate::test_util::run_fixed_step;
+
+    fn path(points: &[(f32, f32)]) -> Vec<Vec2> {
+        points.iter().map(|&(x, y)| Vec2::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_linear_path_stops_at_last_waypoint() {
+        let mut platform = MovingPlatform::new(
+            path(&[(0.0, 0.0), (100.0, 0.0)]),
+            PathMode::Linear,
+            60.0,
+            0.0,
+        );
+
+        let position = platform.advance(Vec2::ZERO, 1.0);
+        assert_eq!(position, Vec2::new(60.0, 0.0));
+
+        let position = platform.advance(position, 1.0);
+        assert_eq!(position, Vec2::new(100.0, 0.0));
+
+        let position = platform.advance(position, 1.0);
+        assert_eq!(position, Vec2::new(100.0, 0.0), "Should stay at the end");
+    }
+
+    #[test]
+    fn test_ping_pong_path_reverses() {
+        let mut platform = MovingPlatform::new(
+            path(&[(0.0, 0.0), (100.0, 0.0), (100.0, 100.0)]),
+            PathMode::PingPong,
+            100.0,
+            0.0,
+        );
+
+        // Out to the far end and half way back along the last leg
+        let position = platform.advance(Vec2::ZERO, 2.5);
+        assert_eq!(position, Vec2::new(100.0, 50.0));
+        assert!(platform.reversing);
+
+        // Back to the start and out again
+        let position = platform.advance(position, 2.0);
+        assert_eq!(position, Vec2::new(50.0, 0.0));
+        assert!(!platform.reversing);
+    }
+
+    #[test]
+    fn test_loop_path_wraps_to_first_waypoint() {
+        let mut platform = MovingPlatform::new(
+            path(&[(0.0, 0.0), (100.0, 0.0), (100.0, 100.0)]),
+            PathMode::Loop,
+            100.0,
+            0.0,
+        );
+
+        // Two legs, then part of the closing diagonal back to the start
+        let position = platform.advance(Vec2::ZERO, 2.0);
+        assert_eq!(position, Vec2::new(100.0, 100.0));
+        assert_eq!(platform.target, 0);
+
+        let position = platform.advance(position, 1.0);
+        assert!(position.x < 100.0 && position.y < 100.0);
+    }
+
+    #[test]
+    fn test_platform_pauses_at_waypoints() {
+        let mut platform = MovingPlatform::new(
+            path(&[(0.0, 0.0), (100.0, 0.0)]),
+            PathMode::PingPong,
+            100.0,
+            0.5,
+        );
+
+        let position = platform.advance(Vec2::ZERO, 1.25);
+        assert_eq!(
+            position,
+            Vec2::new(100.0, 0.0),
+            "Should wait at the waypoint"
+        );
+
+        let position = platform.advance(position, 0.5);
+        assert_eq!(position, Vec2::new(75.0, 0.0));
+    }
+
+    fn spawn_platform(app: &mut App, geometry: LevelGeometry, velocity: Vec2) -> Entity {
+        let start = Vec2::new(geometry.x, geometry.y);
+        app.world
+            .spawn((
+                geometry,
+                MovingPlatform::new(
+                    vec![start, start + velocity * 10.0],
+                    PathMode::Linear,
+                    velocity.length(),
+                    0.0,
+                ),
+            ))
+            .id()
+    }
+
+    fn spawn_body(app: &mut App, x: f32, y: f32, grounded: bool) -> Entity {
+        app.world
+            .spawn((
+                Position::new(x, y),
+                Velocity::default(),
+                Collider::new(32.0, 32.0),
+                GroundedState {
+                    is_grounded: grounded,
+                    ground_normal: if grounded {
+                        Vec2::new(0.0, -1.0)
+                    } else {
+                        Vec2::ZERO
+                    },
+                    ..Default::default()
+                },
+                WallClimbState::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_grounded_body_carried_by_platform() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        let platform = spawn_platform(
+            &mut app,
+            LevelGeometry::new(0.0, 100.0, 100.0, 16.0),
+            Vec2::new(60.0, -30.0),
+        );
+        let body = spawn_body(&mut app, 30.0, 68.0, true);
+
+        for _ in 0..30 {
+            run_fixed_step(&mut app);
+        }
+
+        let geometry = app.world.get::<LevelGeometry>(platform).unwrap();
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!((position.x - 60.0).abs() < 0.01, "x = {}", position.x);
+        assert!(
+            (position.y + 32.0 - geometry.y).abs() < 0.01,
+            "Should stay on top of the rising platform"
+        );
+        assert!(app.world.get::<GroundedState>(body).unwrap().is_grounded);
+    }
+
+    #[test]
+    fn test_body_carried_on_the_step_it_jumps() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        spawn_platform(
+            &mut app,
+            LevelGeometry::new(0.0, 100.0, 100.0, 16.0),
+            Vec2::new(60.0, 0.0),
+        );
+        let body = spawn_body(&mut app, 30.0, 68.0, true);
+
+        app.world.get_mut::<Velocity>(body).unwrap().y = -400.0;
+        run_fixed_step(&mut app);
+
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!((position.x - 31.0).abs() < 0.01, "x = {}", position.x);
+        assert!(position.y < 68.0, "Should have left the platform");
+
+        // Once airborne the platform no longer carries it
+        let airborne = *position;
+        run_fixed_step(&mut app);
+        let position = app.world.get::<Position>(body).unwrap();
+        assert_eq!(position.x, airborne.x);
+    }
+
+    #[test]
+    fn test_clinging_body_carried_by_wall_platform() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        spawn_platform(
+            &mut app,
+            LevelGeometry::new(100.0, 0.0, 32.0, 200.0),
+            Vec2::new(0.0, -60.0),
+        );
+        let body = spawn_body(&mut app, 68.0, 100.0, false);
+        *app.world.get_mut::<WallClimbState>(body).unwrap() = WallClimbState {
+            is_clinging: true,
+            wall_normal: Vec2::new(-1.0, 0.0),
+        };
+
+        for _ in 0..30 {
+            run_fixed_step(&mut app);
+        }
+
+        let position = app.world.get::<Position>(body).unwrap();
+        assert!((position.y - 70.0).abs() < 0.01, "y = {}", position.y);
+        assert_eq!(position.x, 68.0);
+    }
+
+    #[test]
+    fn test_platform_does_not_carry_bodies_beside_it() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world
+            .spawn(LevelGeometry::new(-200.0, 100.0, 200.0, 16.0));
+        spawn_platform(
+            &mut app,
+            LevelGeometry::new(100.0, 100.0, 100.0, 16.0),
+            Vec2::new(60.0, 0.0),
+        );
+        let body = spawn_body(&mut app, -50.0, 68.0, true);
+
+        for _ in 0..10 {
+            run_fixed_step(&mut app);
+        }
+
+        assert_eq!(app.world.get::<Position>(body).unwrap().x, -50.0);
+    }
+}
@@ -1,113 +1,392 @@
 use crate::components::{
-    AbilitySet, Collider, GroundedState, LevelGeometry, Player, PlayerIntent, Position, Velocity,
-    WallClimbState,
+    AbilitySet, AnimationState, Collider, CollisionLayers, CoyoteTime, FacingDirection,
+    ForceVolume, GroundedState, JumpBuffer, LevelGeometry, Player, PlayerIntent, Position,
+    SwingState, SwingTarget, Velocity, WallClimbState,
+};
+use crate::enums::{Ability, InputAction};
+use crate::plugins::broadphase::SpatialHash;
+use crate::plugins::controls::{ActionMap, ActionState, update_action_state};
+use crate::plugins::physics::{
+    CollisionContacts, DropThrough, GROUND_CHECK_EPSILON, WALL_CHECK_DISTANCE,
+    is_on_one_way_platform, nearby_geometry,
 };
-use crate::enums::Ability;
-use crate::plugins::physics::swept_aabb_collision;
+use crate::plugins::query::PhysicsQuery;
+use crate::plugins::render_transform::PreviousPosition;
+use crate::plugins::schedule::{GameSet, configure_game_sets};
+use crate::plugins::volume::volume_effects_at;
+use crate::tuning::{CharacterTuning, MovementTuning};
 use bevy::prelude::*;
 
-/// Physics constants
-pub const MOVE_SPEED: f32 = 200.0; // pixels per second
-pub const BASE_JUMP_VELOCITY: f32 = -400.0; // pixels per second (negative = up)
-pub const HIGH_JUMP_VELOCITY: f32 = -600.0; // pixels per second
-pub const WALL_CLIMB_SPEED: f32 = 150.0; // pixels per second
-pub const WALL_JUMP_HORIZONTAL_VELOCITY: f32 = 250.0; // pixels per second
-pub const WALL_JUMP_VERTICAL_VELOCITY: f32 = -450.0; // pixels per second (negative = up)
+/// Whether PlayerIntent holds input that no fixed tick has read yet
+/// Frames can run without a fixed tick, so input is merged until one consumes it
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct InputLatch {
+    pub pending: bool,
+}
+
+/// Intent fed to the player every frame in place of the keyboard, for tests and replays
+/// While this resource exists keyboard input is ignored
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScriptedIntent(pub PlayerIntent);
+
+/// Size of the player's collider
+pub const PLAYER_WIDTH: f32 = 32.0;
+pub const PLAYER_HEIGHT: f32 = 64.0;
+
+/// Everything the player character needs, with its top-left corner at `position`
+#[allow(clippy::type_complexity)]
+pub fn player_bundle(
+    position: Position,
+    abilities: AbilitySet,
+) -> (
+    Player,
+    PlayerIntent,
+    Position,
+    PreviousPosition,
+    Velocity,
+    Collider,
+    CollisionLayers,
+    CollisionContacts,
+    GroundedState,
+    WallClimbState,
+    AbilitySet,
+    AnimationState,
+    FacingDirection,
+    Transform,
+    (CoyoteTime, JumpBuffer, SwingTarget),
+) {
+    (
+        Player,
+        PlayerIntent::default(),
+        position,
+        PreviousPosition(position),
+        Velocity::default(),
+        Collider::new(PLAYER_WIDTH, PLAYER_HEIGHT),
+        CollisionLayers::player(),
+        CollisionContacts::default(),
+        GroundedState::default(),
+        WallClimbState::default(),
+        abilities,
+        AnimationState::default(),
+        FacingDirection::Right,
+        Transform::default(),
+        (
+            CoyoteTime::default(),
+            JumpBuffer::default(),
+            SwingTarget::default(),
+        ),
+    )
+}
 
 /// Plugin for player character logic and state
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                process_input_system,
-                update_wall_cling_state,
-                apply_horizontal_movement_system,
-                apply_wall_climb_movement_system,
-                apply_jump_system,
+        configure_game_sets(app);
+        app.init_resource::<InputLatch>()
+            .init_resource::<MovementTuning>()
+            .init_resource::<ActionMap>()
+            .init_resource::<ActionState>()
+            .add_systems(
+                PreUpdate,
+                (
+                    (update_action_state, process_input_system)
+                        .chain()
+                        .run_if(not(resource_exists::<ScriptedIntent>())),
+                    apply_scripted_intent_system.run_if(resource_exists::<ScriptedIntent>()),
+                )
+                    .in_set(GameSet::Input),
             )
-                .chain(),
-        );
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_wall_cling_state.in_set(GameSet::Intent),
+                    (
+                        apply_horizontal_movement_system,
+                        apply_wall_climb_movement_system,
+                        apply_jump_system,
+                        apply_swim_system,
+                    )
+                        .chain()
+                        .in_set(GameSet::Movement),
+                    consume_input_system.in_set(GameSet::PostPhysics),
+                ),
+            );
     }
 }
 
-/// Process keyboard input and translate to PlayerIntent
+/// Fill PlayerIntent from the bound actions and latch it until a fixed tick reads it
 fn process_input_system(
-    keyboard: Res<Input<KeyCode>>,
+    actions: Res<ActionState>,
+    mut latch: ResMut<InputLatch>,
+    mut query: Query<&mut PlayerIntent, With<Player>>,
+) {
+    for mut intent in query.iter_mut() {
+        let frame = PlayerIntent {
+            move_left: actions.pressed(InputAction::MoveLeft),
+            move_right: actions.pressed(InputAction::MoveRight),
+            move_x: actions.strength(InputAction::MoveRight)
+                - actions.strength(InputAction::MoveLeft),
+            move_up: actions.pressed(InputAction::Up),
+            move_down: actions.pressed(InputAction::Down),
+            jump_pressed: actions.pressed(InputAction::Jump),
+            jump_just_pressed: actions.just_pressed(InputAction::Jump),
+            jump_just_released: actions.just_released(InputAction::Jump),
+            swing_pressed: actions.pressed(InputAction::Swing),
+            swing_released: actions.just_released(InputAction::Swing),
+        };
+
+        if latch.pending {
+            // Keep anything the previous frames latched that no tick has seen yet
+            intent.move_left |= frame.move_left;
+            intent.move_right |= frame.move_right;
+            if frame.move_x.abs() > intent.move_x.abs() {
+                intent.move_x = frame.move_x;
+            }
+            intent.move_up |= frame.move_up;
+            intent.move_down |= frame.move_down;
+            intent.jump_pressed |= frame.jump_pressed;
+            intent.jump_just_pressed |= frame.jump_just_pressed;
+            intent.jump_just_released |= frame.jump_just_released;
+            intent.swing_pressed |= frame.swing_pressed;
+            intent.swing_released |= frame.swing_released;
+        } else {
+            *intent = frame;
+        }
+    }
+
+    latch.pending = true;
+}
+
+/// Copy the scripted intent into PlayerIntent for the next fixed tick
+fn apply_scripted_intent_system(
+    script: Res<ScriptedIntent>,
+    mut latch: ResMut<InputLatch>,
     mut query: Query<&mut PlayerIntent, With<Player>>,
 ) {
     for mut intent in query.iter_mut() {
-        intent.move_left = keyboard.pressed(KeyCode::Left) || keyboard.pressed(KeyCode::A);
-        intent.move_right = keyboard.pressed(KeyCode::Right) || keyboard.pressed(KeyCode::D);
-        intent.jump_pressed = keyboard.pressed(KeyCode::Space);
-        intent.jump_just_released = keyboard.just_released(KeyCode::Space);
+        *intent = script.0;
     }
+    latch.pending = true;
 }
 
-/// Apply horizontal movement based on player intent
+/// Mark latched input as read, so one-shot events only reach the first tick after them
+fn consume_input_system(
+    mut latch: ResMut<InputLatch>,
+    mut query: Query<&mut PlayerIntent, With<Player>>,
+) {
+    if !latch.pending {
+        return;
+    }
+
+    for mut intent in query.iter_mut() {
+        intent.jump_just_pressed = false;
+        intent.jump_just_released = false;
+        intent.swing_released = false;
+    }
+    latch.pending = false;
+}
+
+/// Accelerate toward the speed the horizontal input asks for, on the ground, in the air or
+/// swimming, where the target is the water's swim speed
+/// Reversing direction gets the turnaround boost, and slippery ground scales every rate down
+#[allow(clippy::type_complexity)]
 fn apply_horizontal_movement_system(
-    mut query: Query<(&PlayerIntent, &mut Velocity, &GroundedState), With<Player>>,
+    mut query: Query<
+        (
+            &PlayerIntent,
+            &mut Velocity,
+            &GroundedState,
+            &WallClimbState,
+            Option<&SwingState>,
+            Option<&CharacterTuning>,
+            &Position,
+            &Collider,
+        ),
+        With<Player>,
+    >,
+    volume_query: Query<&ForceVolume>,
+    tuning: Res<MovementTuning>,
+    time: Res<Time<Fixed>>,
 ) {
-    for (intent, mut velocity, grounded) in query.iter_mut() {
-        // Only apply horizontal movement when grounded
-        if grounded.is_grounded {
-            if intent.move_right && !intent.move_left {
-                velocity.x = MOVE_SPEED;
-            } else if intent.move_left && !intent.move_right {
-                velocity.x = -MOVE_SPEED;
-            } else {
-                velocity.x = 0.0;
-            }
+    let delta_time = time.delta_seconds();
+
+    for (intent, mut velocity, grounded, wall_state, swing, character, position, collider) in
+        query.iter_mut()
+    {
+        // Clinging and swinging have their own movement
+        if wall_state.is_clinging || swing.is_some() {
+            continue;
         }
+        let tuning = character.map_or(&*tuning, |character| &character.0);
+
+        let swim_speed = volume_effects_at(position, collider, volume_query.iter()).swim_speed;
+        let target = intent.horizontal() * swim_speed.unwrap_or(tuning.move_speed);
+        let (acceleration, deceleration, grip) = if swim_speed.is_some() {
+            (tuning.swim_acceleration, tuning.swim_acceleration, 1.0)
+        } else if grounded.is_grounded {
+            (
+                tuning.ground_acceleration,
+                tuning.ground_deceleration,
+                grounded.ground_material.friction.clamp(0.0, 1.0),
+            )
+        } else {
+            (tuning.air_acceleration, tuning.air_deceleration, 1.0)
+        };
+
+        let rate = if velocity.x * target < 0.0 {
+            acceleration * tuning.turnaround_multiplier
+        } else if target.abs() > velocity.x.abs() {
+            acceleration
+        } else {
+            deceleration
+        };
+        let max_change = rate * grip * delta_time;
+        velocity.x += (target - velocity.x).clamp(-max_change, max_change);
     }
 }
 
 /// Apply jump mechanics based on player intent
+/// Jumps fire once per press, up to `coyote_ticks` after leaving the ground and for
+/// `jump_buffer_ticks` after a press that came before landing
+#[allow(clippy::type_complexity)]
 fn apply_jump_system(
+    mut commands: Commands,
     mut query: Query<
         (
+            Entity,
             &PlayerIntent,
             &mut Velocity,
             &GroundedState,
             &AbilitySet,
             &mut WallClimbState,
+            (&mut CoyoteTime, &mut JumpBuffer),
+            Option<&CharacterTuning>,
+            &Position,
+            &Collider,
+            Option<&CollisionLayers>,
         ),
         With<Player>,
     >,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    spatial_hash: Res<SpatialHash>,
+    tuning: Res<MovementTuning>,
 ) {
-    for (intent, mut velocity, grounded, ability_set, mut wall_state) in query.iter_mut() {
+    for (
+        entity,
+        intent,
+        mut velocity,
+        grounded,
+        ability_set,
+        mut wall_state,
+        (mut coyote, mut buffer),
+        character,
+        position,
+        collider,
+        layers,
+    ) in query.iter_mut()
+    {
+        let tuning = character.map_or(&*tuning, |character| &character.0);
+
+        if grounded.is_grounded {
+            coyote.ticks_left = tuning.coyote_ticks;
+        } else if velocity.y < 0.0 {
+            // Rising from a jump or bounce rather than walking off a ledge
+            coyote.ticks_left = 0;
+        }
+        if intent.jump_just_pressed {
+            // The press counts on its own tick, then waits up to jump_buffer_ticks more
+            buffer.ticks_left = tuning.jump_buffer_ticks + 1;
+        }
+
+        let wants_jump = buffer.ticks_left > 0;
+        let can_jump = grounded.is_grounded || coyote.ticks_left > 0;
+        buffer.ticks_left = buffer.ticks_left.saturating_sub(1);
+        if !grounded.is_grounded {
+            coyote.ticks_left = coyote.ticks_left.saturating_sub(1);
+        }
+
         // Wall jump - takes priority over normal jump
-        if wall_state.is_clinging && intent.jump_pressed {
+        if wall_state.is_clinging && wants_jump {
             // Apply velocity away from wall and upward
             let wall_normal = wall_state.wall_normal;
 
             // Horizontal velocity is in direction of wall normal (away from wall)
-            velocity.x = wall_normal.x * WALL_JUMP_HORIZONTAL_VELOCITY;
+            velocity.x = wall_normal.x * tuning.wall_jump_horizontal_velocity;
 
             // Vertical velocity is upward
-            velocity.y = WALL_JUMP_VERTICAL_VELOCITY;
+            velocity.y = tuning.wall_jump_vertical_velocity;
 
             // Exit wall-cling state
             wall_state.is_clinging = false;
+            buffer.ticks_left = 0;
 
-            return; // Don't process normal jump
+            continue; // Don't process normal jump
         }
 
-        // Apply jump velocity when grounded and jump pressed
-        if grounded.is_grounded && intent.jump_pressed {
+        // Down + jump on a one-way platform drops through it instead of jumping
+        if grounded.is_grounded
+            && wants_jump
+            && intent.move_down
+            && is_on_one_way_platform(
+                position,
+                collider,
+                nearby_geometry(
+                    &spatial_hash,
+                    &geometry_query,
+                    position,
+                    collider,
+                    layers,
+                    GROUND_CHECK_EPSILON,
+                    None,
+                ),
+            )
+        {
+            commands.entity(entity).insert(DropThrough {
+                from_y: position.y + collider.offset_y + collider.height,
+            });
+            buffer.ticks_left = 0;
+            continue;
+        }
+
+        if wants_jump && can_jump {
             // Check if high jump ability is unlocked
             if ability_set.has(Ability::HighJump) {
-                velocity.y = HIGH_JUMP_VELOCITY;
+                velocity.y = tuning.high_jump_velocity;
             } else {
-                velocity.y = BASE_JUMP_VELOCITY;
+                velocity.y = tuning.base_jump_velocity;
+            }
+            buffer.ticks_left = 0;
+            coyote.ticks_left = 0;
+
+            // A buffered jump whose button is already up is a short hop
+            if !intent.jump_pressed && !intent.jump_just_released {
+                velocity.y *= tuning.jump_release_damping;
             }
         }
 
         // Variable jump height - reduce velocity on key release during ascent
         if intent.jump_just_released && velocity.y < 0.0 {
-            velocity.y *= 0.5;
+            velocity.y *= tuning.jump_release_damping;
+        }
+    }
+}
+
+/// Stroke upward while jump is held in water
+/// Swimming sideways is handled with the rest of horizontal movement
+fn apply_swim_system(
+    mut query: Query<(&PlayerIntent, &mut Velocity, &Position, &Collider), With<Player>>,
+    volume_query: Query<&ForceVolume>,
+) {
+    for (intent, mut velocity, position, collider) in query.iter_mut() {
+        let effects = volume_effects_at(position, collider, volume_query.iter());
+        let Some(swim_speed) = effects.swim_speed else {
+            continue;
+        };
+
+        if intent.jump_pressed {
+            velocity.y = -swim_speed;
         }
     }
 }
@@ -123,12 +402,15 @@ fn update_wall_cling_state(
             &GroundedState,
             &Position,
             &Collider,
+            Option<&CollisionLayers>,
         ),
         With<Player>,
     >,
-    geometry_query: Query<&LevelGeometry>,
+    physics: PhysicsQuery,
 ) {
-    for (intent, ability_set, mut wall_state, grounded, position, collider) in query.iter_mut() {
+    for (intent, ability_set, mut wall_state, grounded, position, collider, layers) in
+        query.iter_mut()
+    {
         // Can only enter wall-cling if:
         // 1. Wall climb ability is unlocked
         // 2. Adjacent to a wall (wall_normal is non-zero)
@@ -140,31 +422,24 @@ fn update_wall_cling_state(
 
         // Check if player has reached the top of the wall
         if wall_state.is_clinging {
-            let mut wall_continues_above = false;
-
-            // Check if there's still wall above the player
-            let check_above = Vec2::new(0.0, -10.0); // Check 10 pixels above
-            for geometry in geometry_query.iter() {
-                // Check if wall continues above current position
-                if let Some((time, normal)) =
-                    swept_aabb_collision(position, collider, geometry, check_above)
-                {
-                    // If we hit a wall above and it's the same wall (same normal direction)
-                    if time < 1.0
-                        && normal.x.abs() > 0.5
-                        && ((normal.x > 0.0 && wall_state.wall_normal.x > 0.0)
-                            || (normal.x < 0.0 && wall_state.wall_normal.x < 0.0))
-                    {
-                        wall_continues_above = true;
-                        break;
-                    }
-                }
-            }
+            // Cast from the player's head toward the wall to check it continues that high
+            let head = Vec2::new(
+                position.x + collider.offset_x + collider.width / 2.0,
+                position.y + collider.offset_y,
+            );
+            let wall_continues_above = physics
+                .raycast(
+                    head,
+                    -wall_state.wall_normal,
+                    collider.width / 2.0 + WALL_CHECK_DISTANCE,
+                    layers.copied().unwrap_or_default(),
+                )
+                .is_some_and(|hit| hit.normal == wall_state.wall_normal);
 
             // If no wall above, exit wall-cling (reached top)
             if !wall_continues_above {
                 wall_state.is_clinging = false;
-                return;
+                continue;
             }
         }
 
@@ -185,16 +460,26 @@ fn update_wall_cling_state(
 
 /// Apply wall climb movement when in wall-cling state
 fn apply_wall_climb_movement_system(
-    mut query: Query<(&PlayerIntent, &WallClimbState, &mut Velocity), With<Player>>,
+    mut query: Query<
+        (
+            &PlayerIntent,
+            &WallClimbState,
+            &mut Velocity,
+            Option<&CharacterTuning>,
+        ),
+        With<Player>,
+    >,
+    tuning: Res<MovementTuning>,
 ) {
-    for (intent, wall_state, mut velocity) in query.iter_mut() {
+    for (intent, wall_state, mut velocity, character) in query.iter_mut() {
+        let tuning = character.map_or(&*tuning, |character| &character.0);
         if wall_state.is_clinging {
             // Allow vertical movement input
             if intent.move_left || intent.move_right {
                 // Determine vertical movement based on up/down keys
                 // For now, we'll use a simple approach where holding the direction key
                 // allows climbing up, and not holding it allows sliding down slowly
-                velocity.y = -WALL_CLIMB_SPEED; // Climb up
+                velocity.y = -tuning.wall_climb_speed; // Climb up
             } else {
                 velocity.y = 0.0; // Stay in place
             }
@@ -209,214 +494,15 @@ fn apply_wall_climb_movement_system(
 mod tests {
     use super::*;
     use crate::components::*;
-
-    fn create_test_player() -> (PlayerIntent, Velocity, GroundedState) {
-        (
-            PlayerIntent::default(),
-            Velocity::default(),
-            GroundedState {
-                is_grounded: true,
-                ground_normal: Vec2::new(0.0, 1.0),
-            },
-        )
-    }
-
-    #[test]
-    fn test_horizontal_movement_right() {
-        let (mut intent, mut velocity, grounded) = create_test_player();
-        intent.move_right = true;
-        intent.move_left = false;
-
-        // Simulate the system
-        if grounded.is_grounded && intent.move_right && !intent.move_left {
-            velocity.x = MOVE_SPEED;
-        }
-
-        assert_eq!(velocity.x, MOVE_SPEED);
-    }
-
-    #[test]
-    fn test_horizontal_movement_left() {
-        let (mut intent, mut velocity, grounded) = create_test_player();
-        intent.move_left = true;
-        intent.move_right = false;
-
-        // Simulate the system
-        if grounded.is_grounded && intent.move_left && !intent.move_right {
-            velocity.x = -MOVE_SPEED;
-        }
-
-        assert_eq!(velocity.x, -MOVE_SPEED);
-    }
-
-    #[test]
-    fn test_horizontal_movement_both_keys() {
-        let (mut intent, mut velocity, grounded) = create_test_player();
-        intent.move_left = true;
-        intent.move_right = true;
-
-        // Simulate the system
-        if grounded.is_grounded {
-            if intent.move_right && !intent.move_left {
-                velocity.x = MOVE_SPEED;
-            } else if intent.move_left && !intent.move_right {
-                velocity.x = -MOVE_SPEED;
-            } else {
-                velocity.x = 0.0;
-            }
-        }
-
-        assert_eq!(velocity.x, 0.0);
-    }
-
-    #[test]
-    fn test_horizontal_movement_no_keys() {
-        let (intent, mut velocity, grounded) = create_test_player();
-
-        // Simulate the system
-        if grounded.is_grounded {
-            if intent.move_right && !intent.move_left {
-                velocity.x = MOVE_SPEED;
-            } else if intent.move_left && !intent.move_right {
-                velocity.x = -MOVE_SPEED;
-            } else {
-                velocity.x = 0.0;
-            }
-        }
-
-        assert_eq!(velocity.x, 0.0);
-    }
-
-    #[test]
-    fn test_jump_initial_velocity() {
-        let (mut intent, mut velocity, grounded) = create_test_player();
-        intent.jump_pressed = true;
-
-        // Simulate the system
-        if grounded.is_grounded && intent.jump_pressed {
-            velocity.y = BASE_JUMP_VELOCITY;
-        }
-
-        assert_eq!(velocity.y, BASE_JUMP_VELOCITY);
-    }
-
-    #[test]
-    fn test_jump_not_grounded() {
-        let (mut intent, mut velocity, mut grounded) = create_test_player();
-        grounded.is_grounded = false;
-        intent.jump_pressed = true;
-        let initial_velocity = velocity.y;
-
-        // Simulate the system
-        if grounded.is_grounded && intent.jump_pressed {
-            velocity.y = BASE_JUMP_VELOCITY;
-        }
-
-        assert_eq!(velocity.y, initial_velocity);
-    }
-
-    #[test]
-    fn test_variable_jump_height() {
-        let (mut intent, mut velocity, _grounded) = create_test_player();
-        velocity.y = -300.0; // Ascending
-        intent.jump_just_released = true;
-
-        // Simulate the system
-        if intent.jump_just_released && velocity.y < 0.0 {
-            velocity.y *= 0.5;
-        }
-
-        assert_eq!(velocity.y, -150.0);
-    }
-
-    #[test]
-    fn test_variable_jump_no_effect_when_descending() {
-        let (mut intent, mut velocity, _grounded) = create_test_player();
-        velocity.y = 100.0; // Descending
-        intent.jump_just_released = true;
-
-        // Simulate the system
-        if intent.jump_just_released && velocity.y < 0.0 {
-            velocity.y *= 0.5;
-        }
-
-        assert_eq!(velocity.y, 100.0);
-    }
-
-    #[test]
-    fn test_no_horizontal_movement_when_airborne() {
-        let (mut intent, mut velocity, mut grounded) = create_test_player();
-        grounded.is_grounded = false;
-        intent.move_right = true;
-        velocity.x = 50.0; // Some existing velocity
-
-        // Simulate the system
-        if grounded.is_grounded && intent.move_right && !intent.move_left {
-            velocity.x = MOVE_SPEED;
-        }
-
-        // Velocity should remain unchanged when airborne
-        assert_eq!(velocity.x, 50.0);
-    }
-
-    #[test]
-    fn test_high_jump_increases_velocity() {
-        let (mut intent, mut velocity, grounded) = create_test_player();
-        let mut ability_set = AbilitySet::new();
-        ability_set.add(Ability::HighJump);
-        intent.jump_pressed = true;
-
-        // Simulate the system with high jump
-        if grounded.is_grounded && intent.jump_pressed {
-            if ability_set.has(Ability::HighJump) {
-                velocity.y = HIGH_JUMP_VELOCITY;
-            } else {
-                velocity.y = BASE_JUMP_VELOCITY;
-            }
-        }
-
-        assert_eq!(velocity.y, HIGH_JUMP_VELOCITY);
-        assert!(velocity.y.abs() > BASE_JUMP_VELOCITY.abs());
-    }
-
-    #[test]
-    fn test_base_jump_without_high_jump_ability() {
-        let (mut intent, mut velocity, grounded) = create_test_player();
-        let ability_set = AbilitySet::new(); // No abilities
-        intent.jump_pressed = true;
-
-        // Simulate the system without high jump
-        if grounded.is_grounded && intent.jump_pressed {
-            if ability_set.has(Ability::HighJump) {
-                velocity.y = HIGH_JUMP_VELOCITY;
-            } else {
-                velocity.y = BASE_JUMP_VELOCITY;
-            }
-        }
-
-        assert_eq!(velocity.y, BASE_JUMP_VELOCITY);
-    }
-
-    #[test]
-    fn test_high_jump_only_when_grounded() {
-        let (mut intent, mut velocity, mut grounded) = create_test_player();
-        grounded.is_grounded = false;
-        let mut ability_set = AbilitySet::new();
-        ability_set.add(Ability::HighJump);
-        intent.jump_pressed = true;
-        let initial_velocity = velocity.y;
-
-        // Simulate the system
-        if grounded.is_grounded && intent.jump_pressed {
-            if ability_set.has(Ability::HighJump) {
-                velocity.y = HIGH_JUMP_VELOCITY;
-            } else {
-                velocity.y = BASE_JUMP_VELOCITY;
-            }
-        }
-
-        // Velocity should remain unchanged when airborne
-        assert_eq!(velocity.y, initial_velocity);
+    use crate::enums::VolumeEffect;
+    use crate::harness::{SimulationHarness, block_level};
+    use crate::plugins::PhysicsPlugin;
+    use crate::test_util::run_fixed_step;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn tuning() -> MovementTuning {
+        MovementTuning::default()
     }
 
     #[test]
@@ -431,6 +517,7 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
         intent.move_left = true; // Pressing toward wall
 
@@ -467,6 +554,7 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
         intent.move_left = true; // Pressing toward wall
 
@@ -504,6 +592,7 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: true, // Grounded
             ground_normal: Vec2::new(0.0, -1.0),
+            ..Default::default()
         };
         intent.move_left = true; // Pressing toward wall
 
@@ -535,6 +624,7 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
         let wall_state = WallClimbState {
             is_clinging: true,
@@ -568,7 +658,7 @@ mod tests {
         // Simulate wall climb movement
         if wall_state.is_clinging {
             if intent.move_left || intent.move_right {
-                velocity.y = -WALL_CLIMB_SPEED;
+                velocity.y = -tuning().wall_climb_speed;
             } else {
                 velocity.y = 0.0;
             }
@@ -576,7 +666,8 @@ mod tests {
         }
 
         assert_eq!(
-            velocity.y, -WALL_CLIMB_SPEED,
+            velocity.y,
+            -tuning().wall_climb_speed,
             "Should climb at wall climb speed"
         );
         assert_eq!(
@@ -597,6 +688,7 @@ mod tests {
         let grounded = GroundedState {
             is_grounded: false,
             ground_normal: Vec2::ZERO,
+            ..Default::default()
         };
 
         // Simulate wall-cling state update
@@ -621,198 +713,767 @@ mod tests {
         );
     }
 
+    /// The head-height probe update_wall_cling_state casts toward the wall
+    fn wall_beside_head(position: &Position, collider: &Collider, wall: &LevelGeometry) -> bool {
+        use crate::plugins::physics::WALL_CHECK_DISTANCE;
+        use crate::plugins::query::raycast_geometry;
+
+        let head = Vec2::new(position.x + collider.width / 2.0, position.y);
+        raycast_geometry(
+            head,
+            Vec2::new(-1.0, 0.0),
+            collider.width / 2.0 + WALL_CHECK_DISTANCE,
+            wall,
+        )
+        .is_some_and(|(_, normal)| normal == Vec2::new(1.0, 0.0))
+    }
+
     #[test]
-    fn test_wall_jump_from_left_wall() {
-        let intent = PlayerIntent {
-            jump_pressed: true,
+    fn test_wall_top_transition_exits_cling() {
+        // When player reaches top of wall (no wall above), should exit wall-cling
+        let position = Position::new(32.0, 100.0); // Adjacent to wall, head level with its top
+        let collider = Collider::new(32.0, 32.0);
+        let wall = LevelGeometry {
+            x: 0.0,
+            y: 0.0,
+            width: 32.0,
+            height: 100.0, // Wall ends at y=100
             ..Default::default()
         };
-        let mut velocity = Velocity::new(0.0, 0.0);
-        let wall_state = WallClimbState {
-            is_clinging: true,
-            wall_normal: Vec2::new(1.0, 0.0), // Wall on left, normal points right
-        };
-
-        // Simulate wall jump
-        if wall_state.is_clinging && intent.jump_pressed {
-            let wall_normal = wall_state.wall_normal;
-            velocity.x = wall_normal.x * WALL_JUMP_HORIZONTAL_VELOCITY;
-            velocity.y = WALL_JUMP_VERTICAL_VELOCITY;
-        }
 
         assert!(
-            velocity.x > 0.0,
-            "Should jump away from left wall (positive x)"
-        );
-        assert!(velocity.y < 0.0, "Should jump upward (negative y)");
-        assert_eq!(
-            velocity.x, WALL_JUMP_HORIZONTAL_VELOCITY,
-            "Horizontal velocity should match constant"
-        );
-        assert_eq!(
-            velocity.y, WALL_JUMP_VERTICAL_VELOCITY,
-            "Vertical velocity should match constant"
+            !wall_beside_head(&position, &collider, &wall),
+            "Should detect that wall does not continue above"
         );
     }
 
     #[test]
-    fn test_wall_jump_from_right_wall() {
-        let intent = PlayerIntent {
-            jump_pressed: true,
+    fn test_wall_continues_when_not_at_top() {
+        // When player is not at top of wall, wall should continue above
+        // Player needs to be positioned adjacent to the wall, not inside it
+        let position = Position::new(35.0, 50.0); // Adjacent to wall
+        let collider = Collider::new(32.0, 32.0);
+        let wall = LevelGeometry {
+            x: 0.0,
+            y: 0.0,
+            width: 32.0,
+            height: 200.0, // Tall wall
             ..Default::default()
         };
-        let mut velocity = Velocity::new(0.0, 0.0);
-        let wall_state = WallClimbState {
-            is_clinging: true,
-            wall_normal: Vec2::new(-1.0, 0.0), // Wall on right, normal points left
-        };
 
-        // Simulate wall jump
-        if wall_state.is_clinging && intent.jump_pressed {
-            let wall_normal = wall_state.wall_normal;
-            velocity.x = wall_normal.x * WALL_JUMP_HORIZONTAL_VELOCITY;
-            velocity.y = WALL_JUMP_VERTICAL_VELOCITY;
-        }
+        assert!(
+            wall_beside_head(&position, &collider, &wall),
+            "Should detect wall when not at top"
+        );
+    }
+
+    /// App whose frames never run a fixed tick on their own, so tests control the tick count
+    fn player_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins((PlayerPlugin, PhysicsPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+        app.init_resource::<Input<KeyCode>>();
+        app
+    }
+
+    /// One frame of input followed by one fixed tick
+    fn run_frame(app: &mut App) {
+        app.update();
+        run_fixed_step(app);
+    }
 
+    #[test]
+    fn test_cling_holds_on_wall_and_releases_at_top() {
+        let mut app = player_app();
+
+        app.world.spawn(LevelGeometry::new(0.0, 0.0, 32.0, 200.0));
+        let player = app
+            .world
+            .spawn((
+                Player,
+                PlayerIntent::default(),
+                Position::new(32.0, 100.0),
+                Velocity::default(),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState {
+                    is_clinging: false,
+                    wall_normal: Vec2::new(1.0, 0.0),
+                },
+                AbilitySet::from(vec![Ability::WallClimb]),
+            ))
+            .id();
+
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Left);
+        run_frame(&mut app);
+        run_frame(&mut app);
         assert!(
-            velocity.x < 0.0,
-            "Should jump away from right wall (negative x)"
+            app.world.get::<WallClimbState>(player).unwrap().is_clinging,
+            "Should keep clinging while the wall continues beside the head"
         );
-        assert!(velocity.y < 0.0, "Should jump upward (negative y)");
-        assert_eq!(
-            velocity.x, -WALL_JUMP_HORIZONTAL_VELOCITY,
-            "Horizontal velocity should match constant"
+
+        app.world.get_mut::<Position>(player).unwrap().y = -10.0;
+        run_frame(&mut app);
+        assert!(
+            !app.world.get::<WallClimbState>(player).unwrap().is_clinging,
+            "Should release at the top of the wall"
         );
+    }
+
+    #[test]
+    fn test_down_jump_drops_through_one_way_platform() {
+        let mut app = player_app();
+
+        app.world.spawn(LevelGeometry {
+            one_way: true,
+            ..LevelGeometry::new(-100.0, 0.0, 300.0, 16.0)
+        });
+        let player = app
+            .world
+            .spawn((
+                Player,
+                PlayerIntent::default(),
+                Position::new(0.0, -32.0),
+                Velocity::default(),
+                Collider::new(32.0, 32.0),
+                GroundedState {
+                    is_grounded: true,
+                    ground_normal: Vec2::new(0.0, -1.0),
+                    ..Default::default()
+                },
+                WallClimbState::default(),
+                AbilitySet::new(),
+                CoyoteTime::default(),
+                JumpBuffer::default(),
+            ))
+            .id();
+
+        let mut keyboard = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard.press(KeyCode::Down);
+        keyboard.press(KeyCode::Space);
+        run_frame(&mut app);
+
+        let drop_through = app.world.get::<DropThrough>(player);
+        assert_eq!(drop_through, Some(&DropThrough { from_y: 0.0 }));
         assert_eq!(
-            velocity.y, WALL_JUMP_VERTICAL_VELOCITY,
-            "Vertical velocity should match constant"
+            app.world.get::<Velocity>(player).unwrap().y,
+            0.0,
+            "Should drop instead of jumping"
         );
     }
 
+    fn spawn_grounded_player(app: &mut App) -> Entity {
+        app.world
+            .spawn(LevelGeometry::new(-100.0, 0.0, 300.0, 32.0));
+        app.world
+            .spawn((
+                Player,
+                PlayerIntent::default(),
+                Position::new(0.0, -32.0),
+                Velocity::default(),
+                Collider::new(32.0, 32.0),
+                GroundedState {
+                    is_grounded: true,
+                    ground_normal: Vec2::new(0.0, -1.0),
+                    ..Default::default()
+                },
+                WallClimbState::default(),
+                AbilitySet::new(),
+                CoyoteTime::default(),
+                JumpBuffer::default(),
+            ))
+            .id()
+    }
+
     #[test]
-    fn test_wall_jump_exits_cling_state() {
-        let intent = PlayerIntent {
-            jump_pressed: true,
-            ..Default::default()
-        };
-        let mut wall_state = WallClimbState {
-            is_clinging: true,
-            wall_normal: Vec2::new(1.0, 0.0),
-        };
+    fn test_tap_between_fixed_ticks_is_latched() {
+        let mut app = player_app();
+        let player = spawn_grounded_player(&mut app);
+
+        // Tap jump within one frame, then run a second frame before any fixed tick
+        let mut keyboard = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        keyboard.release(KeyCode::Space);
+        app.update();
+        app.world.resource_mut::<Input<KeyCode>>().clear();
+        app.update();
+
+        run_fixed_step(&mut app);
+
+        let velocity = app.world.get::<Velocity>(player).unwrap();
+        assert!(velocity.y < 0.0, "The tap should still jump");
+    }
 
-        // Simulate wall jump
-        if wall_state.is_clinging && intent.jump_pressed {
-            wall_state.is_clinging = false;
-        }
+    #[test]
+    fn test_release_reaches_only_the_next_fixed_tick() {
+        let mut app = player_app();
+        let player = spawn_grounded_player(&mut app);
+
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Space);
+        run_frame(&mut app);
+
+        let mut keyboard = app.world.resource_mut::<Input<KeyCode>>();
+        keyboard.clear();
+        keyboard.release(KeyCode::Space);
+        app.update();
+        assert!(
+            app.world
+                .get::<PlayerIntent>(player)
+                .unwrap()
+                .jump_just_released
+        );
 
+        run_fixed_step(&mut app);
         assert!(
-            !wall_state.is_clinging,
-            "Should exit wall-cling state after wall jump"
+            !app.world
+                .get::<PlayerIntent>(player)
+                .unwrap()
+                .jump_just_released
         );
+        assert!(!app.world.resource::<InputLatch>().pending);
+
+        // Held state survives extra ticks in the same frame
+        app.world.resource_mut::<Input<KeyCode>>().clear();
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Right);
+        app.update();
+        run_fixed_step(&mut app);
+        run_fixed_step(&mut app);
+        assert!(app.world.get::<PlayerIntent>(player).unwrap().move_right);
     }
 
     #[test]
-    fn test_wall_jump_has_both_horizontal_and_vertical_components() {
-        let intent = PlayerIntent {
-            jump_pressed: true,
+    fn test_player_slides_on_ice() {
+        let mut app = player_app();
+        app.world.spawn((
+            LevelGeometry::new(-100.0, 0.0, 2000.0, 32.0),
+            SurfaceMaterial::ice(),
+        ));
+        let player = app
+            .world
+            .spawn((
+                Player,
+                PlayerIntent::default(),
+                Position::new(0.0, -32.0),
+                Velocity::new(tuning().move_speed, 0.0),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState::default(),
+                AbilitySet::new(),
+            ))
+            .id();
+
+        // Letting go of the keys on ice keeps most of the speed for a while
+        for _ in 0..5 {
+            run_frame(&mut app);
+        }
+        let velocity = app.world.get::<Velocity>(player).unwrap();
+        assert!(
+            velocity.x > tuning().move_speed * 0.7,
+            "Ice should not stop the player at once, got {}",
+            velocity.x
+        );
+
+        // Pressing the other way takes several ticks to turn around
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Left);
+        run_frame(&mut app);
+        assert!(app.world.get::<Velocity>(player).unwrap().x > 0.0);
+    }
+
+    #[test]
+    fn test_player_swims_up_in_water() {
+        let mut app = player_app();
+        app.world.spawn(ForceVolume::new(
+            -200.0,
+            -400.0,
+            400.0,
+            400.0,
+            VolumeEffect::Water {
+                buoyancy: 0.0,
+                drag: 1.0,
+                swim_speed: 150.0,
+            },
+        ));
+        let player = spawn_grounded_player(&mut app);
+
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Space);
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .press(KeyCode::Right);
+        for _ in 0..10 {
+            run_frame(&mut app);
+        }
+
+        // Swimming replaces the jump and stays within the swim speed
+        let velocity = app.world.get::<Velocity>(player).unwrap();
+        assert!(velocity.y < 0.0, "Player should swim upward");
+        assert!(Vec2::new(velocity.x, velocity.y).length() <= 150.0 + 0.01);
+        assert!(app.world.get::<Position>(player).unwrap().y < -32.0);
+    }
+
+    /// Floor ending at x = 0 with a pit past it, player standing near the edge
+    fn ledge_harness() -> SimulationHarness {
+        SimulationHarness::new(block_level(
+            "ledge",
+            Position::new(-100.0, 336.0),
+            &[(-400.0, 400.0, 400.0, 64.0), (-400.0, 900.0, 1200.0, 64.0)],
+        ))
+    }
+
+    fn held(move_right: bool, jump_pressed: bool) -> PlayerIntent {
+        PlayerIntent {
+            move_right,
+            jump_pressed,
             ..Default::default()
-        };
-        let mut velocity = Velocity::new(0.0, 0.0);
-        let wall_state = WallClimbState {
-            is_clinging: true,
-            wall_normal: Vec2::new(1.0, 0.0),
-        };
+        }
+    }
 
-        // Simulate wall jump
-        if wall_state.is_clinging && intent.jump_pressed {
-            let wall_normal = wall_state.wall_normal;
-            velocity.x = wall_normal.x * WALL_JUMP_HORIZONTAL_VELOCITY;
-            velocity.y = WALL_JUMP_VERTICAL_VELOCITY;
+    fn is_grounded(harness: &SimulationHarness) -> bool {
+        harness.player_get::<GroundedState>().unwrap().is_grounded
+    }
+
+    /// Step until the player's grounded state flips, returning how many ticks it took
+    fn step_until_grounded_is(
+        harness: &mut SimulationHarness,
+        grounded: bool,
+        intent: PlayerIntent,
+    ) -> usize {
+        for tick in 1..=300 {
+            harness.step(intent);
+            if is_grounded(harness) == grounded {
+                return tick;
+            }
         }
+        panic!("Grounded never became {grounded}");
+    }
 
+    #[test]
+    fn test_holding_jump_jumps_once() {
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+
+        harness.step(held(false, true));
+        assert!(harness.player_get::<Velocity>().unwrap().y < 0.0);
+
+        step_until_grounded_is(&mut harness, true, held(false, true));
+        harness.step_n(10, held(false, true));
+        assert!(is_grounded(&harness), "Holding jump should not jump again");
+    }
+
+    #[test]
+    fn test_coyote_time_allows_late_jump() {
+        let coyote_ticks = tuning().coyote_ticks as usize;
+
+        for (airborne_ticks, should_jump) in [
+            (2, true),
+            (coyote_ticks - 1, true),
+            (coyote_ticks + 2, false),
+        ] {
+            let mut harness = ledge_harness();
+            harness.step_n(5, PlayerIntent::default());
+            step_until_grounded_is(&mut harness, false, held(true, false));
+
+            harness.step_n(airborne_ticks, held(true, false));
+            harness.step(held(true, true));
+
+            let velocity_y = harness.player_get::<Velocity>().unwrap().y;
+            assert_eq!(
+                velocity_y < 0.0,
+                should_jump,
+                "Jump {airborne_ticks} ticks after leaving the ledge, velocity {velocity_y}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_jump_buffered_before_landing() {
+        let buffer_ticks = tuning().jump_buffer_ticks as usize;
+
+        // Find how many ticks a jump from the floor takes to land
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        let airtime = step_until_grounded_is(&mut harness, true, PlayerIntent::default());
+
+        for (early_ticks, should_jump) in
+            [(2, true), (buffer_ticks, true), (buffer_ticks + 3, false)]
+        {
+            let mut harness = ledge_harness();
+            harness.step_n(5, PlayerIntent::default());
+            harness.step(held(false, true));
+            harness.step_n(airtime - early_ticks, PlayerIntent::default());
+
+            // Press and hold jump a few ticks before touching down
+            harness.step_n(early_ticks + 2, held(false, true));
+
+            let velocity_y = harness.player_get::<Velocity>().unwrap().y;
+            assert_eq!(
+                velocity_y < 0.0,
+                should_jump,
+                "Jump pressed {early_ticks} ticks before landing, velocity {velocity_y}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_buffered_tap_is_short_hop() {
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        let airtime = step_until_grounded_is(&mut harness, true, PlayerIntent::default());
+
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        harness.step_n(airtime - 3, PlayerIntent::default());
+        harness.step(held(false, true));
+        harness.step_n(5, PlayerIntent::default());
+
+        let velocity_y = harness.player_get::<Velocity>().unwrap().y;
+        let tuning = tuning();
+        let hop = tuning.base_jump_velocity * tuning.jump_release_damping;
         assert!(
-            velocity.x.abs() > 0.0,
-            "Wall jump should have horizontal component"
+            velocity_y < 0.0 && velocity_y >= hop,
+            "Expected a short hop slower than {hop}, got {velocity_y}"
         );
+    }
+
+    fn velocity_y(harness: &SimulationHarness) -> f32 {
+        harness.player_get::<Velocity>().unwrap().y
+    }
+
+    /// Velocity gravity adds over one fixed tick
+    fn gravity_per_tick() -> f32 {
+        tuning().gravity / 60.0
+    }
+
+    #[test]
+    fn test_jump_velocity_depends_on_high_jump() {
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        assert_eq!(velocity_y(&harness), tuning().base_jump_velocity);
+
+        let mut harness = ledge_harness();
+        harness.grant(Ability::HighJump);
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        assert_eq!(velocity_y(&harness), tuning().high_jump_velocity);
+    }
+
+    #[test]
+    fn test_no_jump_while_airborne() {
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        harness.step_n(3, PlayerIntent::default());
+        let rising = velocity_y(&harness);
+
+        harness.step(held(false, true));
+
+        assert!((velocity_y(&harness) - (rising + gravity_per_tick())).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_releasing_jump_early_cuts_ascent() {
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step_n(3, held(false, true));
+        let rising = velocity_y(&harness);
+        assert!(rising < 0.0);
+
+        harness.step(PlayerIntent::default());
+
+        let expected = rising * tuning().jump_release_damping + gravity_per_tick();
         assert!(
-            velocity.y.abs() > 0.0,
-            "Wall jump should have vertical component"
+            (velocity_y(&harness) - expected).abs() < 0.01,
+            "Expected {expected} after release, got {}",
+            velocity_y(&harness)
         );
     }
 
     #[test]
-    fn test_no_wall_jump_when_not_clinging() {
-        let intent = PlayerIntent {
-            jump_pressed: true,
+    fn test_releasing_jump_while_falling_changes_nothing() {
+        let mut harness = ledge_harness();
+        harness.step_n(5, PlayerIntent::default());
+        harness.step(held(false, true));
+        while velocity_y(&harness) <= 0.0 {
+            harness.step(held(false, true));
+        }
+        let falling = velocity_y(&harness);
+
+        harness.step(PlayerIntent::default());
+
+        let expected = falling + gravity_per_tick();
+        assert!((velocity_y(&harness) - expected).abs() < 0.01);
+    }
+
+    /// Player with WallClimb clinging to a tall wall on the given side
+    fn clinging_harness(wall_on_left: bool) -> SimulationHarness {
+        let wall_x = if wall_on_left { 0.0 } else { 200.0 };
+        let mut harness = SimulationHarness::new(block_level(
+            "wall",
+            Position::new(100.0, 336.0),
+            &[
+                (-400.0, 400.0, 1000.0, 64.0),
+                (wall_x, -1000.0, 32.0, 1400.0),
+            ],
+        ));
+        harness.grant(Ability::WallClimb);
+        harness.step_n(5, PlayerIntent::default());
+
+        // Jump once, then drift into the wall until it catches the player
+        let toward_wall = PlayerIntent {
+            move_left: wall_on_left,
+            move_right: !wall_on_left,
             ..Default::default()
         };
-        let mut velocity = Velocity::new(0.0, 0.0);
-        let wall_state = WallClimbState {
-            is_clinging: false, // Not clinging
-            wall_normal: Vec2::new(1.0, 0.0),
+        harness.step(PlayerIntent {
+            jump_pressed: true,
+            ..toward_wall
+        });
+        harness.step_n(60, toward_wall);
+        assert!(
+            harness.player_get::<WallClimbState>().unwrap().is_clinging,
+            "Player should be clinging to the wall"
+        );
+        harness
+    }
+
+    #[test]
+    fn test_wall_jump_pushes_away_from_wall() {
+        for (wall_on_left, away) in [(true, 1.0), (false, -1.0)] {
+            let mut harness = clinging_harness(wall_on_left);
+            harness.step(PlayerIntent {
+                move_left: wall_on_left,
+                move_right: !wall_on_left,
+                jump_pressed: true,
+                ..Default::default()
+            });
+
+            let velocity = *harness.player_get::<Velocity>().unwrap();
+            let tuning = tuning();
+            assert_eq!(velocity.x, away * tuning.wall_jump_horizontal_velocity);
+            assert!(
+                (velocity.y - (tuning.wall_jump_vertical_velocity + gravity_per_tick())).abs()
+                    < 0.01
+            );
+            assert!(!harness.player_get::<WallClimbState>().unwrap().is_clinging);
+        }
+    }
+
+    #[test]
+    fn test_no_wall_jump_without_wall_climb() {
+        let mut harness = clinging_harness(true);
+        let player = harness.player();
+        harness
+            .world_mut()
+            .entity_mut(player)
+            .insert(AbilitySet::new());
+        let against_wall = PlayerIntent {
+            move_left: true,
+            ..Default::default()
         };
+        harness.step(against_wall);
+        assert!(!harness.player_get::<WallClimbState>().unwrap().is_clinging);
+        let before = velocity_y(&harness);
 
-        // Simulate wall jump attempt
-        if wall_state.is_clinging && intent.jump_pressed {
-            let wall_normal = wall_state.wall_normal;
-            velocity.x = wall_normal.x * WALL_JUMP_HORIZONTAL_VELOCITY;
-            velocity.y = WALL_JUMP_VERTICAL_VELOCITY;
+        // Jumping against the wall in mid-air does nothing
+        harness.step(PlayerIntent {
+            jump_pressed: true,
+            ..against_wall
+        });
+
+        assert!((velocity_y(&harness) - (before + gravity_per_tick())).abs() < 0.01);
+        assert!(harness.player_get::<Velocity>().unwrap().x <= 0.0);
+    }
+
+    /// Long flat floor with the player standing on it
+    fn flat_harness() -> SimulationHarness {
+        let mut harness = SimulationHarness::new(block_level(
+            "flat",
+            Position::new(0.0, 336.0),
+            &[(-2000.0, 400.0, 4000.0, 64.0)],
+        ));
+        harness.step_n(5, PlayerIntent::default());
+        harness
+    }
+
+    fn moving(move_left: bool, move_right: bool) -> PlayerIntent {
+        PlayerIntent {
+            move_left,
+            move_right,
+            ..Default::default()
         }
+    }
 
-        assert_eq!(velocity.x, 0.0, "Should not wall jump when not clinging");
-        assert_eq!(velocity.y, 0.0, "Should not wall jump when not clinging");
+    fn velocity_x(harness: &SimulationHarness) -> f32 {
+        harness.player_get::<Velocity>().unwrap().x
     }
 
     #[test]
-    fn test_wall_top_transition_exits_cling() {
-        // When player reaches top of wall (no wall above), should exit wall-cling
-        let position = Position::new(50.0, 100.0);
-        let collider = Collider::new(32.0, 32.0);
-        let wall = LevelGeometry {
-            x: 0.0,
-            y: 0.0,
-            width: 32.0,
-            height: 100.0, // Wall ends at y=100
-        };
+    fn test_ground_acceleration_ramps_to_move_speed() {
+        let tuning = tuning();
+        let mut harness = flat_harness();
 
-        // Check if wall continues above (should not)
-        let check_above = Vec2::new(0.0, -10.0);
-        let result = swept_aabb_collision(&position, &collider, &wall, check_above);
+        harness.step(moving(false, true));
+        let first_tick = tuning.ground_acceleration / 60.0;
+        assert!((velocity_x(&harness) - first_tick).abs() < 0.01);
 
-        // Player is at top of wall, no collision above
-        let wall_continues_above = if let Some((time, normal)) = result {
-            time < 1.0 && normal.x.abs() > 0.5
-        } else {
-            false
-        };
+        harness.step_n(30, moving(false, true));
+        assert_eq!(velocity_x(&harness), tuning.move_speed);
 
-        assert!(
-            !wall_continues_above,
-            "Should detect that wall does not continue above"
+        harness.step_n(30, moving(true, false));
+        assert_eq!(velocity_x(&harness), -tuning.move_speed);
+    }
+
+    #[test]
+    fn test_turnaround_is_boosted() {
+        let tuning = tuning();
+        let mut harness = flat_harness();
+        harness.step_n(30, moving(false, true));
+
+        harness.step(moving(true, false));
+        let turned = tuning.ground_acceleration * tuning.turnaround_multiplier / 60.0;
+        assert!((velocity_x(&harness) - (tuning.move_speed - turned)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_no_keys_or_both_keys_decelerate_to_stop() {
+        let tuning = tuning();
+        let stopping_ticks = (tuning.move_speed / (tuning.ground_deceleration / 60.0)).ceil();
+
+        for intent in [moving(false, false), moving(true, true)] {
+            let mut harness = flat_harness();
+            harness.step_n(30, moving(false, true));
+
+            harness.step(intent);
+            assert!(
+                velocity_x(&harness) > 0.0,
+                "Should slow down, not stop at once"
+            );
+
+            harness.step_n(stopping_ticks as usize, intent);
+            assert_eq!(velocity_x(&harness), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_air_control_is_weaker_than_ground() {
+        let tuning = tuning();
+        let mut harness = flat_harness();
+
+        harness.step(PlayerIntent {
+            jump_pressed: true,
+            ..Default::default()
+        });
+        harness.step(PlayerIntent {
+            move_right: true,
+            jump_pressed: true,
+            ..Default::default()
+        });
+
+        assert!(!harness.player_get::<GroundedState>().unwrap().is_grounded);
+        assert!((velocity_x(&harness) - tuning.air_acceleration / 60.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_analog_input_scales_target_speed() {
+        let mut harness = flat_harness();
+        harness.step_n(
+            30,
+            PlayerIntent {
+                move_right: true,
+                move_x: 0.5,
+                ..Default::default()
+            },
         );
+
+        assert_eq!(velocity_x(&harness), tuning().move_speed * 0.5);
     }
 
     #[test]
-    fn test_wall_continues_when_not_at_top() {
-        use crate::plugins::physics::WALL_CHECK_DISTANCE;
+    fn test_analog_input_scales_swim_speed() {
+        let mut harness = flat_harness();
+        harness.world_mut().spawn(ForceVolume::new(
+            -200.0,
+            0.0,
+            400.0,
+            400.0,
+            VolumeEffect::Water {
+                buoyancy: 0.0,
+                drag: 0.0,
+                swim_speed: 150.0,
+            },
+        ));
+
+        harness.step_n(
+            30,
+            PlayerIntent {
+                move_right: true,
+                move_x: 0.5,
+                ..Default::default()
+            },
+        );
+        assert!((velocity_x(&harness) - 75.0).abs() < 0.01);
 
-        // When player is not at top of wall, wall should continue above
-        // Player needs to be positioned adjacent to the wall, not inside it
-        let position = Position::new(35.0, 50.0); // Adjacent to wall
-        let collider = Collider::new(32.0, 32.0);
-        let wall = LevelGeometry {
-            x: 0.0,
-            y: 0.0,
-            width: 32.0,
-            height: 200.0, // Tall wall
-        };
+        harness.step_n(30, moving(false, true));
+        assert!((velocity_x(&harness) - 150.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_swimming_accelerates_toward_swim_speed() {
+        let tuning = tuning();
+        let mut harness = flat_harness();
+        harness.world_mut().spawn(ForceVolume::new(
+            -200.0,
+            0.0,
+            400.0,
+            400.0,
+            VolumeEffect::Water {
+                buoyancy: 0.0,
+                drag: 0.0,
+                swim_speed: 150.0,
+            },
+        ));
+
+        // Swimming keeps the speed it had and builds up from there, rather than jumping to it
+        harness.step(moving(false, true));
+        let first_tick = tuning.swim_acceleration / 60.0;
+        assert!((velocity_x(&harness) - first_tick).abs() < 0.01);
 
-        // Check if wall continues above
-        // The check needs to look for wall in the horizontal direction (where the wall is)
-        let check_left = Vec2::new(-WALL_CHECK_DISTANCE, 0.0);
-        let result = swept_aabb_collision(&position, &collider, &wall, check_left);
+        harness.step(moving(false, true));
+        assert!((velocity_x(&harness) - 2.0 * first_tick).abs() < 0.01);
 
-        // Should detect wall on the left
-        assert!(result.is_some(), "Should detect wall when not at top");
+        // Turning around gets the same boost as on land
+        harness.step(moving(true, false));
+        let turned = 2.0 * first_tick - first_tick * tuning.turnaround_multiplier;
+        assert!((velocity_x(&harness) - turned).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_character_tuning_overrides_global_tuning() {
+        let mut harness = flat_harness();
+        let player = harness.player();
+        harness
+            .world_mut()
+            .entity_mut(player)
+            .insert(CharacterTuning(MovementTuning {
+                move_speed: 320.0,
+                ..tuning()
+            }));
+
+        harness.step_n(30, moving(false, true));
+        assert_eq!(velocity_x(&harness), 320.0);
     }
 }
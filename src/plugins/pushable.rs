@@ -0,0 +1,485 @@
+use crate::components::{
+    Collider, CollisionLayers, GroundedState, LevelGeometry, Player, Position, Velocity,
+    WallClimbState,
+};
+use crate::plugins::broadphase::SpatialHash;
+use crate::plugins::physics::{
+    COLLISION_EPSILON, move_and_slide, nearby_geometry, swept_aabb_collision,
+};
+use crate::plugins::trigger::colliders_overlap;
+use bevy::prelude::*;
+
+/// Dynamic body such as a crate or boulder
+/// It falls and lands like any other body, and its LevelGeometry follows its collider so
+/// the player and other pushables can stand on it and push against it
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Pushable {
+    pub mass: f32, // Relative to the player; heavier bodies are pushed more slowly
+}
+
+impl Pushable {
+    /// Everything a pushable needs, with its top-left corner at `position`
+    pub fn bundle(
+        mass: f32,
+        position: Position,
+        width: f32,
+        height: f32,
+    ) -> (
+        Self,
+        Position,
+        Velocity,
+        Collider,
+        LevelGeometry,
+        GroundedState,
+        WallClimbState,
+    ) {
+        (
+            Self { mass },
+            position,
+            Velocity::default(),
+            Collider::new(width, height),
+            LevelGeometry::new(position.x, position.y, width, height),
+            GroundedState::default(),
+            WallClimbState::default(),
+        )
+    }
+}
+
+/// Area that is pressed while the player or a pushable overlaps it
+/// Place it so it pokes up above the floor, so bodies resting on the floor overlap it
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct PressurePlate {
+    pub id: String,
+    pub pressed: bool,
+}
+
+impl PressurePlate {
+    pub fn new(id: String) -> Self {
+        Self { id, pressed: false }
+    }
+}
+
+/// Pushable bodies as push_bodies reads and moves them
+type PushableQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Pushable,
+        &'static mut Position,
+        &'static Collider,
+        Option<&'static CollisionLayers>,
+    ),
+>;
+
+/// Move pushables the player walks into, and the pushables those run into in turn
+/// A pushable takes its pusher's remaining movement divided by its mass, sliding against the
+/// level, and the pusher's own sweep then stops against where it ended up. Every pushable the
+/// pusher reaches first is pushed, so a tall body moves a whole stack, and each is pushed at
+/// most once per tick.
+#[allow(clippy::type_complexity)]
+pub(crate) fn push_bodies(
+    pushers: Query<
+        (&Position, &Velocity, &Collider, Option<&CollisionLayers>),
+        (With<Player>, Without<Pushable>),
+    >,
+    mut pushables: PushableQuery,
+    geometry_query: Query<(Entity, &LevelGeometry)>,
+    spatial_hash: Res<SpatialHash>,
+    time: Res<Time<Fixed>>,
+) {
+    let delta_time = time.delta_seconds();
+    if delta_time <= 0.0 {
+        return;
+    }
+
+    let mut pushed = Vec::new();
+    for (position, velocity, collider, layers) in pushers.iter() {
+        let movement = velocity.x * delta_time;
+        if movement == 0.0 {
+            continue;
+        }
+
+        let pusher = Pusher {
+            entity: None,
+            position: *position,
+            collider: *collider,
+            layers: layers.copied(),
+        };
+        push_ahead(
+            &pusher,
+            movement,
+            &mut pushables,
+            &geometry_query,
+            &spatial_hash,
+            delta_time,
+            &mut pushed,
+        );
+    }
+}
+
+/// Body moving `movement` along x that pushes whatever is in its way
+struct Pusher {
+    entity: Option<Entity>,
+    position: Position,
+    collider: Collider,
+    layers: Option<CollisionLayers>,
+}
+
+/// Push the pushables a pusher reaches first, clearing the way ahead of each before moving it
+fn push_ahead(
+    pusher: &Pusher,
+    movement: f32,
+    pushables: &mut PushableQuery,
+    geometry_query: &Query<(Entity, &LevelGeometry)>,
+    spatial_hash: &SpatialHash,
+    delta_time: f32,
+    pushed: &mut Vec<Entity>,
+) {
+    let sweep = Vec2::new(movement, 0.0);
+    let geometry = geometry_while_pushing(
+        pusher,
+        movement.abs(),
+        pushables,
+        geometry_query,
+        spatial_hash,
+    );
+    let hits: Vec<(Entity, f32, Vec2)> = geometry
+        .iter()
+        .filter_map(|(entity, geometry)| {
+            swept_aabb_collision(&pusher.position, &pusher.collider, geometry, sweep)
+                .map(|(time, normal)| (*entity, time, normal))
+        })
+        .collect();
+    let Some(earliest) = hits.iter().map(|(_, time, _)| *time).min_by(f32::total_cmp) else {
+        return;
+    };
+
+    for (entity, time, normal) in hits {
+        // Only what is reached first is pushed; anything later is behind it
+        if (time - earliest) * movement.abs() > COLLISION_EPSILON
+            || normal.x * movement >= 0.0
+            || normal.y.abs() >= 0.5
+            || pushed.contains(&entity)
+        {
+            continue;
+        }
+        let Ok((_, pushable, position, collider, layers)) = pushables.get(entity) else {
+            continue;
+        };
+        let push = movement * (1.0 - time) / pushable.mass.max(1.0);
+        let body = Pusher {
+            entity: Some(entity),
+            position: *position,
+            collider: *collider,
+            layers: layers.copied(),
+        };
+        pushed.push(entity);
+
+        push_ahead(
+            &body,
+            push,
+            pushables,
+            geometry_query,
+            spatial_hash,
+            delta_time,
+            pushed,
+        );
+        let others =
+            geometry_while_pushing(&body, push.abs(), pushables, geometry_query, spatial_hash);
+        let Ok((_, _, mut position, _, _)) = pushables.get_mut(entity) else {
+            continue;
+        };
+        move_and_slide(
+            &mut position,
+            &mut Velocity::new(push / delta_time, 0.0),
+            &body.collider,
+            others.iter().map(|(other, geometry)| (*other, geometry)),
+            delta_time,
+        );
+    }
+}
+
+/// Geometry within `reach` of a pusher other than its own, with pushables placed where they
+/// have been pushed to so far this tick
+fn geometry_while_pushing(
+    pusher: &Pusher,
+    reach: f32,
+    pushables: &PushableQuery,
+    geometry_query: &Query<(Entity, &LevelGeometry)>,
+    spatial_hash: &SpatialHash,
+) -> Vec<(Entity, LevelGeometry)> {
+    nearby_geometry(
+        spatial_hash,
+        geometry_query,
+        &pusher.position,
+        &pusher.collider,
+        pusher.layers.as_ref(),
+        reach,
+        None,
+    )
+    .into_iter()
+    .filter(|(other, _)| Some(*other) != pusher.entity)
+    .map(|(other, geometry)| {
+        let mut geometry = geometry.clone();
+        if let Ok((_, _, position, collider, _)) = pushables.get(other) {
+            geometry.x = position.x + collider.offset_x;
+            geometry.y = position.y + collider.offset_y;
+        }
+        (other, geometry)
+    })
+    .collect()
+}
+
+/// Keep each pushable's LevelGeometry on its collider after it moves
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_pushable_geometry(
+    mut query: Query<
+        (&Position, &Collider, &mut LevelGeometry),
+        (With<Pushable>, Changed<Position>),
+    >,
+) {
+    for (position, collider, mut geometry) in query.iter_mut() {
+        let x = position.x + collider.offset_x;
+        let y = position.y + collider.offset_y;
+        if geometry.x != x || geometry.y != y {
+            geometry.x = x;
+            geometry.y = y;
+        }
+    }
+}
+
+/// Press plates that the player or a pushable overlaps, and release the rest
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_pressure_plates(
+    mut plates: Query<(&mut PressurePlate, &Position, &Collider)>,
+    bodies: Query<(&Position, &Collider), Or<(With<Player>, With<Pushable>)>>,
+) {
+    for (mut plate, plate_position, plate_collider) in plates.iter_mut() {
+        let pressed = bodies.iter().any(|(position, collider)| {
+            colliders_overlap(position, collider, plate_position, plate_collider)
+        });
+        // Only write on change, so Changed<PressurePlate> marks presses and releases
+        if plate.pressed != pressed {
+            plate.pressed = pressed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::PhysicsPlugin;
+    use crate::test_util::run_fixed_step;
+
+    fn pushable_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app.world
+            .spawn(LevelGeometry::new(-500.0, 32.0, 2000.0, 32.0));
+        app
+    }
+
+    fn spawn_player(app: &mut App, x: f32) -> Entity {
+        app.world
+            .spawn((
+                Player,
+                Position::new(x, 0.0),
+                Velocity::default(),
+                Collider::new(32.0, 32.0),
+                GroundedState::default(),
+                WallClimbState::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_pushables_fall_and_stack() {
+        let mut app = pushable_app();
+        let bottom = app
+            .world
+            .spawn(Pushable::bundle(
+                1.0,
+                Position::new(0.0, -100.0),
+                32.0,
+                32.0,
+            ))
+            .id();
+        let top = app
+            .world
+            .spawn(Pushable::bundle(
+                1.0,
+                Position::new(8.0, -200.0),
+                32.0,
+                32.0,
+            ))
+            .id();
+
+        for _ in 0..90 {
+            run_fixed_step(&mut app);
+        }
+
+        assert!(app.world.get::<Position>(bottom).unwrap().y.abs() < 0.01);
+        let top_position = *app.world.get::<Position>(top).unwrap();
+        assert!((top_position.y + 32.0).abs() < 0.01);
+        assert!(app.world.get::<GroundedState>(top).unwrap().is_grounded);
+
+        // Its geometry follows it for the next body to land on
+        let geometry = app.world.get::<LevelGeometry>(top).unwrap();
+        assert_eq!((geometry.x, geometry.y), (top_position.x, top_position.y));
+    }
+
+    #[test]
+    fn test_player_pushes_by_mass() {
+        let mut app = pushable_app();
+        let light = app
+            .world
+            .spawn(Pushable::bundle(1.0, Position::new(32.0, 0.0), 32.0, 32.0))
+            .id();
+        spawn_player(&mut app, 0.0);
+
+        let mut heavy_app = pushable_app();
+        let heavy = heavy_app
+            .world
+            .spawn(Pushable::bundle(4.0, Position::new(32.0, 0.0), 32.0, 32.0))
+            .id();
+        spawn_player(&mut heavy_app, 0.0);
+
+        // Let everything settle, then the player keeps walking into the crate
+        run_fixed_step(&mut app);
+        run_fixed_step(&mut heavy_app);
+        for _ in 0..10 {
+            for app in [&mut app, &mut heavy_app] {
+                for mut velocity in app
+                    .world
+                    .query_filtered::<&mut Velocity, With<Player>>()
+                    .iter_mut(&mut app.world)
+                {
+                    velocity.x = 120.0;
+                }
+                run_fixed_step(app);
+            }
+        }
+
+        let light_x = app.world.get::<Position>(light).unwrap().x;
+        let heavy_x = heavy_app.world.get::<Position>(heavy).unwrap().x;
+        assert!((light_x - 52.0).abs() < 0.01, "Light crate at {light_x}");
+        assert!((heavy_x - 37.0).abs() < 0.01, "Heavy crate at {heavy_x}");
+    }
+
+    /// Walk the player right into whatever is ahead of it for ten ticks
+    fn push_right(app: &mut App, player: Entity) {
+        run_fixed_step(app);
+        for _ in 0..10 {
+            app.world.get_mut::<Velocity>(player).unwrap().x = 120.0;
+            run_fixed_step(app);
+        }
+    }
+
+    #[test]
+    fn test_tall_player_pushes_stacked_crates_together() {
+        let mut app = pushable_app();
+        let bottom = app
+            .world
+            .spawn(Pushable::bundle(1.0, Position::new(32.0, 0.0), 32.0, 32.0))
+            .id();
+        let top = app
+            .world
+            .spawn(Pushable::bundle(
+                1.0,
+                Position::new(32.0, -32.0),
+                32.0,
+                32.0,
+            ))
+            .id();
+        let player = spawn_player(&mut app, 0.0);
+        {
+            let mut entity = app.world.entity_mut(player);
+            *entity.get_mut::<Position>().unwrap() = Position::new(0.0, -32.0);
+            *entity.get_mut::<Collider>().unwrap() = Collider::new(32.0, 64.0);
+        }
+
+        push_right(&mut app, player);
+
+        let bottom_position = *app.world.get::<Position>(bottom).unwrap();
+        let top_position = *app.world.get::<Position>(top).unwrap();
+        assert!(
+            (bottom_position.x - 52.0).abs() < 0.01,
+            "Bottom crate at {bottom_position:?}"
+        );
+        assert!(
+            (top_position.x - 52.0).abs() < 0.01,
+            "Top crate at {top_position:?}"
+        );
+        assert!((top_position.y + 32.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_pushed_crate_pushes_the_next_one() {
+        let mut app = pushable_app();
+        let first = app
+            .world
+            .spawn(Pushable::bundle(1.0, Position::new(32.0, 0.0), 32.0, 32.0))
+            .id();
+        let second = app
+            .world
+            .spawn(Pushable::bundle(1.0, Position::new(64.0, 0.0), 32.0, 32.0))
+            .id();
+        let player = spawn_player(&mut app, 0.0);
+
+        push_right(&mut app, player);
+
+        let first_x = app.world.get::<Position>(first).unwrap().x;
+        let second_x = app.world.get::<Position>(second).unwrap().x;
+        assert!((first_x - 52.0).abs() < 0.01, "First crate at {first_x}");
+        assert!((second_x - 84.0).abs() < 0.01, "Second crate at {second_x}");
+        assert!((app.world.get::<Position>(player).unwrap().x - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_pushable_stops_at_wall() {
+        let mut app = pushable_app();
+        app.world
+            .spawn(LevelGeometry::new(70.0, -100.0, 32.0, 132.0));
+        let crate_entity = app
+            .world
+            .spawn(Pushable::bundle(1.0, Position::new(32.0, 0.0), 32.0, 32.0))
+            .id();
+        let player = spawn_player(&mut app, 0.0);
+
+        for _ in 0..30 {
+            app.world.get_mut::<Velocity>(player).unwrap().x = 120.0;
+            run_fixed_step(&mut app);
+        }
+
+        assert_eq!(app.world.get::<Position>(crate_entity).unwrap().x, 38.0);
+        assert!((app.world.get::<Position>(player).unwrap().x - 6.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_pushable_presses_plate() {
+        let mut app = pushable_app();
+        let plate = app
+            .world
+            .spawn((
+                PressurePlate::new("door".to_string()),
+                Position::new(100.0, 28.0),
+                Collider::new(32.0, 4.0),
+            ))
+            .id();
+        app.world.spawn(Pushable::bundle(
+            1.0,
+            Position::new(100.0, -64.0),
+            32.0,
+            32.0,
+        ));
+
+        run_fixed_step(&mut app);
+        assert!(!app.world.get::<PressurePlate>(plate).unwrap().pressed);
+
+        for _ in 0..30 {
+            run_fixed_step(&mut app);
+        }
+        assert!(app.world.get::<PressurePlate>(plate).unwrap().pressed);
+    }
+}
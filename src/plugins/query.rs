@@ -0,0 +1,355 @@
+use crate::components::{Collider, CollisionLayers, LevelGeometry, Position};
+use crate::plugins::broadphase::SpatialHash;
+use crate::plugins::physics::{slope_axis, swept_aabb_collision};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// A hit reported by a physics query
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueryHit {
+    pub entity: Entity,
+    pub point: Vec2,   // Where the query touched the geometry
+    pub normal: Vec2,  // Surface normal at the hit, facing back along the query
+    pub distance: f32, // Distance travelled along the query before the hit
+}
+
+/// Read-only ray, shape and overlap queries against level geometry
+/// Candidates come from the broadphase, so queries cost the same on any size of level.
+/// Each query only sees geometry that a body on the given layers would collide with.
+#[derive(SystemParam)]
+pub struct PhysicsQuery<'w, 's> {
+    geometry: Query<'w, 's, (Entity, &'static LevelGeometry)>,
+    spatial_hash: Res<'w, SpatialHash>,
+}
+
+impl PhysicsQuery<'_, '_> {
+    /// Closest geometry hit by a ray within `max_distance`
+    pub fn raycast(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        layers: CollisionLayers,
+    ) -> Option<QueryHit> {
+        let direction = direction.normalize_or_zero();
+        if direction == Vec2::ZERO {
+            return None;
+        }
+
+        let end = origin + direction * max_distance;
+        self.candidates(origin.min(end), origin.max(end), layers)
+            .filter_map(|(entity, geometry)| {
+                let (distance, normal) =
+                    raycast_geometry(origin, direction, max_distance, geometry)?;
+                Some(QueryHit {
+                    entity,
+                    point: origin + direction * distance,
+                    normal,
+                    distance,
+                })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// First geometry a collider hits when moved, using the same sweep as collision
+    /// resolution. The hit point is on the collider's face at the time of impact.
+    pub fn shape_cast(
+        &self,
+        position: &Position,
+        collider: &Collider,
+        movement: Vec2,
+        layers: CollisionLayers,
+    ) -> Option<QueryHit> {
+        let min = Vec2::new(
+            position.x + collider.offset_x,
+            position.y + collider.offset_y,
+        );
+        let max = min + Vec2::new(collider.width, collider.height);
+        let (entity, time, normal) = self
+            .candidates(min.min(min + movement), max.max(max + movement), layers)
+            .filter_map(|(entity, geometry)| {
+                let (time, normal) = swept_aabb_collision(position, collider, geometry, movement)?;
+                Some((entity, time, normal))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+        let center = (min + max) / 2.0 + movement * time;
+        let half_extent =
+            (collider.width * normal.x.abs() + collider.height * normal.y.abs()) / 2.0;
+        Some(QueryHit {
+            entity,
+            point: center - normal * half_extent,
+            normal,
+            distance: movement.length() * time,
+        })
+    }
+
+    /// Geometry overlapping the box, not counting geometry that only touches its edges
+    pub fn overlap_aabb(&self, min: Vec2, max: Vec2, layers: CollisionLayers) -> Vec<Entity> {
+        self.candidates(min, max, layers)
+            .filter(|(_, geometry)| aabb_overlaps_geometry(min, max, geometry))
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+
+    /// Geometry containing the point, including its edges
+    pub fn point_query(&self, point: Vec2, layers: CollisionLayers) -> Vec<Entity> {
+        self.candidates(point, point, layers)
+            .filter(|(_, geometry)| geometry_contains_point(geometry, point))
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+
+    fn candidates(
+        &self,
+        min: Vec2,
+        max: Vec2,
+        layers: CollisionLayers,
+    ) -> impl Iterator<Item = (Entity, &LevelGeometry)> {
+        self.spatial_hash
+            .query(min, max)
+            .into_iter()
+            .filter_map(|entity| self.geometry.get(entity).ok())
+            .filter(move |(_, geometry)| layers.interacts_with(&geometry.layers))
+    }
+}
+
+/// Separating axes of the geometry: x, y and the slope normal, each with the
+/// geometry's extent along it
+fn geometry_axes(geometry: &LevelGeometry) -> impl Iterator<Item = (Vec2, f32, f32)> {
+    [
+        (Vec2::X, geometry.x, geometry.x + geometry.width),
+        (Vec2::Y, geometry.y, geometry.y + geometry.height),
+    ]
+    .into_iter()
+    .chain(slope_axis(geometry))
+}
+
+/// Distance along a unit direction to the geometry and the normal of the face hit
+/// A ray starting inside the geometry hits at distance zero, facing back along the ray
+pub fn raycast_geometry(
+    origin: Vec2,
+    direction: Vec2,
+    max_distance: f32,
+    geometry: &LevelGeometry,
+) -> Option<(f32, Vec2)> {
+    let mut entry = f32::NEG_INFINITY;
+    let mut normal = -direction;
+    let mut exit = f32::INFINITY;
+
+    for (axis, min, max) in geometry_axes(geometry) {
+        let start = origin.dot(axis);
+        let speed = direction.dot(axis);
+        if speed == 0.0 {
+            // Parallel to this axis' faces, so the ray must already be between them
+            // (grazing along a face is not a hit)
+            if start <= min || start >= max {
+                return None;
+            }
+            continue;
+        }
+
+        let (near, far) = if speed > 0.0 { (min, max) } else { (max, min) };
+        let near_time = (near - start) / speed;
+        if near_time > entry {
+            entry = near_time;
+            normal = -axis * speed.signum();
+        }
+        exit = exit.min((far - start) / speed);
+    }
+
+    if entry > exit || exit < 0.0 || entry > max_distance {
+        return None;
+    }
+
+    let (distance, normal) = if entry < 0.0 {
+        (0.0, -direction)
+    } else {
+        (entry, normal)
+    };
+
+    // One-way platforms can only be hit from above, like bodies landing on them
+    if geometry.one_way && normal != Vec2::new(0.0, -1.0) {
+        return None;
+    }
+
+    Some((distance, normal))
+}
+
+/// Whether a box and the geometry overlap by more than touching
+fn aabb_overlaps_geometry(min: Vec2, max: Vec2, geometry: &LevelGeometry) -> bool {
+    let center = (min + max) / 2.0;
+    let size = max - min;
+    geometry_axes(geometry).all(|(axis, geo_min, geo_max)| {
+        let projected = center.dot(axis);
+        let half_extent = (size.x * axis.x.abs() + size.y * axis.y.abs()) / 2.0;
+        projected + half_extent > geo_min && projected - half_extent < geo_max
+    })
+}
+
+/// Whether the point is inside or on the edge of the geometry
+fn geometry_contains_point(geometry: &LevelGeometry, point: Vec2) -> bool {
+    geometry_axes(geometry).all(|(axis, min, max)| {
+        let projected = point.dot(axis);
+        projected >= min && projected <= max
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::GeometryShape;
+    use crate::enums::BoxCorner;
+    use crate::plugins::PhysicsPlugin;
+    use bevy::ecs::system::SystemState;
+
+    #[test]
+    fn test_raycast_hits_box_face() {
+        let wall = LevelGeometry::new(100.0, 0.0, 32.0, 100.0);
+
+        let hit = raycast_geometry(Vec2::new(0.0, 50.0), Vec2::X, 200.0, &wall);
+        assert_eq!(hit, Some((100.0, Vec2::new(-1.0, 0.0))));
+
+        let too_short = raycast_geometry(Vec2::new(0.0, 50.0), Vec2::X, 50.0, &wall);
+        assert!(too_short.is_none());
+
+        let above = raycast_geometry(Vec2::new(0.0, -10.0), Vec2::X, 200.0, &wall);
+        assert!(above.is_none());
+    }
+
+    #[test]
+    fn test_raycast_hits_slope_surface() {
+        // Rises to the right, surface from (0, 100) to (100, 0)
+        let slope = LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::BottomRight),
+            ..LevelGeometry::new(0.0, 0.0, 100.0, 100.0)
+        };
+
+        let (distance, normal) =
+            raycast_geometry(Vec2::new(25.0, -50.0), Vec2::Y, 500.0, &slope).unwrap();
+        assert!((distance - 125.0).abs() < 0.001, "distance = {}", distance);
+        assert!((normal - Vec2::new(-1.0, -1.0).normalize()).length() < 0.001);
+    }
+
+    #[test]
+    fn test_raycast_one_way_platform_only_from_above() {
+        let platform = LevelGeometry {
+            one_way: true,
+            ..LevelGeometry::new(0.0, 100.0, 100.0, 16.0)
+        };
+
+        let down = raycast_geometry(Vec2::new(50.0, 0.0), Vec2::Y, 200.0, &platform);
+        assert_eq!(down, Some((100.0, Vec2::new(0.0, -1.0))));
+
+        let up = raycast_geometry(Vec2::new(50.0, 200.0), -Vec2::Y, 200.0, &platform);
+        assert!(up.is_none());
+    }
+
+    #[test]
+    fn test_raycast_starting_inside_hits_at_zero() {
+        let block = LevelGeometry::new(0.0, 0.0, 100.0, 100.0);
+        let hit = raycast_geometry(Vec2::new(50.0, 50.0), Vec2::X, 10.0, &block);
+        assert_eq!(hit, Some((0.0, Vec2::new(-1.0, 0.0))));
+    }
+
+    fn query_app(geometry: &[LevelGeometry]) -> (App, Vec<Entity>) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        let entities = geometry
+            .iter()
+            .map(|geo| app.world.spawn(geo.clone()).id())
+            .collect();
+        app.update();
+        (app, entities)
+    }
+
+    #[test]
+    fn test_physics_query_raycast_reports_closest_hit() {
+        let (mut app, entities) = query_app(&[
+            LevelGeometry::new(200.0, 0.0, 32.0, 100.0),
+            LevelGeometry::new(100.0, 0.0, 32.0, 100.0),
+        ]);
+        let mut state = SystemState::<PhysicsQuery>::new(&mut app.world);
+        let physics = state.get(&app.world);
+
+        let hit = physics
+            .raycast(
+                Vec2::new(0.0, 50.0),
+                Vec2::new(2.0, 0.0),
+                500.0,
+                CollisionLayers::default(),
+            )
+            .unwrap();
+        assert_eq!(hit.entity, entities[1]);
+        assert_eq!(hit.point, Vec2::new(100.0, 50.0));
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        assert_eq!(hit.distance, 100.0);
+    }
+
+    #[test]
+    fn test_physics_query_shape_cast() {
+        let (mut app, entities) = query_app(&[LevelGeometry::new(-100.0, 100.0, 300.0, 32.0)]);
+        let mut state = SystemState::<PhysicsQuery>::new(&mut app.world);
+        let physics = state.get(&app.world);
+
+        let hit = physics
+            .shape_cast(
+                &Position::new(0.0, 0.0),
+                &Collider::new(32.0, 32.0),
+                Vec2::new(0.0, 100.0),
+                CollisionLayers::default(),
+            )
+            .unwrap();
+        assert_eq!(hit.entity, entities[0]);
+        assert_eq!(hit.normal, Vec2::new(0.0, -1.0));
+        assert!((hit.distance - 68.0).abs() < 0.001);
+        assert!((hit.point - Vec2::new(16.0, 100.0)).length() < 0.001);
+    }
+
+    #[test]
+    fn test_physics_query_overlap_and_point() {
+        let (mut app, entities) = query_app(&[
+            LevelGeometry::new(0.0, 0.0, 100.0, 100.0),
+            LevelGeometry {
+                shape: GeometryShape::Triangle(BoxCorner::BottomRight),
+                ..LevelGeometry::new(100.0, 0.0, 100.0, 100.0)
+            },
+        ]);
+        let mut state = SystemState::<PhysicsQuery>::new(&mut app.world);
+        let physics = state.get(&app.world);
+
+        assert_eq!(
+            physics.overlap_aabb(
+                Vec2::new(90.0, 90.0),
+                Vec2::new(110.0, 99.0),
+                CollisionLayers::default()
+            ),
+            entities
+        );
+        // Touching the box's edge doesn't count as overlapping
+        assert!(
+            physics
+                .overlap_aabb(
+                    Vec2::new(-20.0, 0.0),
+                    Vec2::new(0.0, 10.0),
+                    CollisionLayers::default()
+                )
+                .is_empty()
+        );
+
+        assert_eq!(
+            physics.point_query(Vec2::new(50.0, 50.0), CollisionLayers::default()),
+            vec![entities[0]]
+        );
+        // Above the slope surface is empty, below it is solid
+        assert!(
+            physics
+                .point_query(Vec2::new(120.0, 20.0), CollisionLayers::default())
+                .is_empty()
+        );
+        assert_eq!(
+            physics.point_query(Vec2::new(180.0, 80.0), CollisionLayers::default()),
+            vec![entities[1]]
+        );
+    }
+}
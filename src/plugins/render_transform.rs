@@ -0,0 +1,269 @@
+use crate::components::{LevelGeometry, Position};
+use crate::plugins::schedule::{GameSet, configure_game_sets};
+use bevy::prelude::*;
+
+/// Systems that turn y-down world coordinates into Bevy's y-up Transforms
+/// Everything that writes a rendered Transform from world state runs in this set,
+/// which is part of GameSet::Presentation
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorldToRender;
+
+/// Position at the start of the latest fixed tick
+/// Bodies with this component are rendered between it and Position instead of snapping per tick
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct PreviousPosition(pub Position);
+
+/// Marks a body that was just moved instantly, such as a respawn or level transition
+/// Its next render skips interpolation so it does not sweep across the level; removed once rendered
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Teleported;
+
+/// Plugin that keeps every rendered entity's Transform in step with its world position
+pub struct RenderTransformPlugin;
+
+impl Plugin for RenderTransformPlugin {
+    fn build(&self, app: &mut App) {
+        configure_game_sets(app);
+        app.add_systems(
+            FixedUpdate,
+            snapshot_previous_positions.before(GameSet::Intent),
+        )
+        .add_systems(PostUpdate, sync_render_transforms.in_set(WorldToRender));
+    }
+}
+
+/// Convert a world point (y down) to render space (y up)
+pub fn world_to_render(point: Vec2) -> Vec2 {
+    Vec2::new(point.x, -point.y)
+}
+
+/// Convert a render point (y up) back to world space (y down)
+pub fn render_to_world(point: Vec2) -> Vec2 {
+    Vec2::new(point.x, -point.y)
+}
+
+/// World point a body is drawn at, `alpha` of the way from its previous position to its
+/// current one; bodies without a previous position are drawn where they are
+pub fn interpolated_position(
+    position: &Position,
+    previous: Option<&PreviousPosition>,
+    alpha: f32,
+) -> Vec2 {
+    let current = Vec2::new(position.x, position.y);
+    match previous {
+        Some(previous) => Vec2::new(previous.0.x, previous.0.y).lerp(current, alpha),
+        None => current,
+    }
+}
+
+/// Remember where interpolated bodies start each fixed tick
+fn snapshot_previous_positions(mut query: Query<(&Position, &mut PreviousPosition)>) {
+    for (position, mut previous) in query.iter_mut() {
+        previous.0 = *position;
+    }
+}
+
+/// Place rendered entities at their world position, keeping their depth
+/// Interpolated bodies are drawn the fraction of a tick the fixed clock has accumulated
+/// past their previous position, so motion stays smooth at any frame rate
+/// Geometry has no Position, so it is placed by its top-left corner
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_render_transforms(
+    mut commands: Commands,
+    mut body_query: Query<(
+        Entity,
+        &Position,
+        Option<&mut PreviousPosition>,
+        Has<Teleported>,
+        &mut Transform,
+    )>,
+    mut geometry_query: Query<(&LevelGeometry, &mut Transform), Without<Position>>,
+    time: Res<Time<Fixed>>,
+) {
+    let alpha = time.overstep_percentage();
+
+    for (entity, position, previous, teleported, mut transform) in body_query.iter_mut() {
+        let world = match previous {
+            Some(mut previous) if teleported => {
+                previous.0 = *position;
+                Vec2::new(position.x, position.y)
+            }
+            previous => interpolated_position(position, previous.as_deref(), alpha),
+        };
+        if teleported {
+            commands.entity(entity).remove::<Teleported>();
+        }
+
+        let render = world_to_render(world);
+        transform.translation.x = render.x;
+        transform.translation.y = render.y;
+    }
+
+    for (geometry, mut transform) in geometry_query.iter_mut() {
+        let render = world_to_render(Vec2::new(geometry.x, geometry.y));
+        transform.translation.x = render.x;
+        transform.translation.y = render.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{PowerUp, VisualEffect, VisualEffectType};
+    use crate::enums::Ability;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn render_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(RenderTransformPlugin);
+        app
+    }
+
+    #[test]
+    fn test_world_down_is_render_down() {
+        // A point lower in the world (larger y) is lower on screen (smaller y)
+        let high = world_to_render(Vec2::new(0.0, 100.0));
+        let low = world_to_render(Vec2::new(0.0, 200.0));
+        assert!(low.y < high.y);
+        assert_eq!(
+            world_to_render(Vec2::new(150.0, 250.0)),
+            Vec2::new(150.0, -250.0)
+        );
+    }
+
+    #[test]
+    fn test_render_to_world_round_trips() {
+        let point = Vec2::new(-32.5, 480.0);
+        assert_eq!(render_to_world(world_to_render(point)), point);
+    }
+
+    #[test]
+    fn test_sync_places_bodies_and_keeps_depth() {
+        let mut app = render_app();
+        let power_up = app
+            .world
+            .spawn((
+                PowerUp {
+                    ability: Ability::HighJump,
+                },
+                Position::new(800.0, 200.0),
+                Transform::from_xyz(0.0, 0.0, 5.0),
+            ))
+            .id();
+        let effect = app
+            .world
+            .spawn((
+                VisualEffect::new(VisualEffectType::Jump, 0.3),
+                Position::new(10.0, 20.0),
+                Transform::default(),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world.get::<Transform>(power_up).unwrap().translation,
+            Vec3::new(800.0, -200.0, 5.0)
+        );
+        assert_eq!(
+            app.world.get::<Transform>(effect).unwrap().translation,
+            Vec3::new(10.0, -20.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_sync_places_geometry_by_top_left() {
+        let mut app = render_app();
+        let floor = app
+            .world
+            .spawn((
+                LevelGeometry::new(0.0, 1016.0, 1920.0, 64.0),
+                Transform::default(),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world.get::<Transform>(floor).unwrap().translation,
+            Vec3::new(0.0, -1016.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_sync_follows_moving_position() {
+        let mut app = render_app();
+        let body = app
+            .world
+            .spawn((Position::new(100.0, 200.0), Transform::default()))
+            .id();
+
+        app.update();
+        app.world.get_mut::<Position>(body).unwrap().y = 150.0;
+        app.update();
+
+        assert_eq!(
+            app.world.get::<Transform>(body).unwrap().translation.y,
+            -150.0
+        );
+    }
+
+    fn move_right(mut query: Query<&mut Position>) {
+        for mut position in query.iter_mut() {
+            position.x += 10.0;
+        }
+    }
+
+    #[test]
+    fn test_render_interpolates_within_fixed_tick() {
+        let mut app = render_app();
+        // One and a half ticks per frame leaves the fixed clock halfway to its next tick
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            1.5 / 64.0,
+        )))
+        .add_systems(FixedUpdate, move_right.in_set(GameSet::Movement));
+        let body = app
+            .world
+            .spawn((
+                Position::new(0.0, 0.0),
+                PreviousPosition(Position::new(0.0, 0.0)),
+                Transform::default(),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        assert_eq!(app.world.get::<Position>(body).unwrap().x, 10.0);
+        let rendered = app.world.get::<Transform>(body).unwrap().translation.x;
+        assert!((rendered - 5.0).abs() < 0.01, "Rendered at {rendered}");
+    }
+
+    #[test]
+    fn test_teleported_body_skips_interpolation() {
+        let mut app = render_app();
+        let body = app
+            .world
+            .spawn((
+                Position::new(500.0, 100.0),
+                PreviousPosition(Position::new(0.0, 0.0)),
+                Teleported,
+                Transform::default(),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world.get::<Transform>(body).unwrap().translation,
+            Vec3::new(500.0, -100.0, 0.0)
+        );
+        assert_eq!(
+            app.world.get::<PreviousPosition>(body),
+            Some(&PreviousPosition(Position::new(500.0, 100.0)))
+        );
+        assert!(app.world.get::<Teleported>(body).is_none());
+    }
+}
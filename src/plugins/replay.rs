@@ -0,0 +1,532 @@
+use crate::components::{AbilitySet, Player, PlayerIntent, Position, SwingState};
+use crate::plugins::level::{
+    CurrentLevel, LevelEntityFilter, load_level_from_file, spawn_level_entities,
+};
+use crate::plugins::physics::DropThrough;
+use crate::plugins::player::player_bundle;
+use crate::plugins::render_transform::Teleported;
+use crate::plugins::schedule::{GameSet, configure_game_sets};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Version written to new recordings; files with any other version are rejected
+pub const REPLAY_VERSION: u32 = 1;
+
+/// Every PlayerIntent the fixed ticks of a run read, and the state the run started from
+/// Replaying it from the same level and abilities reproduces the run exactly
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputRecording {
+    pub version: u32,
+    pub level_id: String,
+    pub abilities: AbilitySet, // Abilities the player started with
+    pub inputs: Vec<InputRun>,
+}
+
+/// Intent held for a number of consecutive ticks, stored as (intent bits, analog move_x, ticks)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputRun(pub u16, pub f32, pub u32);
+
+impl InputRecording {
+    pub fn new(level_id: String, abilities: AbilitySet) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            level_id,
+            abilities,
+            inputs: Vec::new(),
+        }
+    }
+
+    /// Append the intent of the next tick
+    pub fn push(&mut self, intent: &PlayerIntent) {
+        let bits = intent_to_bits(intent);
+        match self.inputs.last_mut() {
+            Some(InputRun(last, move_x, ticks)) if *last == bits && *move_x == intent.move_x => {
+                *ticks += 1
+            }
+            _ => self.inputs.push(InputRun(bits, intent.move_x, 1)),
+        }
+    }
+
+    /// Number of ticks recorded
+    pub fn len(&self) -> usize {
+        self.inputs.iter().map(|run| run.2 as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The intent of every tick, in order
+    pub fn intents(&self) -> impl Iterator<Item = PlayerIntent> + '_ {
+        self.inputs.iter().flat_map(|run| {
+            let intent = PlayerIntent {
+                move_x: run.1,
+                ..intent_from_bits(run.0)
+            };
+            std::iter::repeat_n(intent, run.2 as usize)
+        })
+    }
+}
+
+const MOVE_LEFT: u16 = 1 << 0;
+const MOVE_RIGHT: u16 = 1 << 1;
+const MOVE_DOWN: u16 = 1 << 2;
+const JUMP_PRESSED: u16 = 1 << 3;
+const JUMP_JUST_RELEASED: u16 = 1 << 4;
+const JUMP_JUST_PRESSED: u16 = 1 << 5;
+const SWING_PRESSED: u16 = 1 << 6;
+const SWING_RELEASED: u16 = 1 << 7;
+const MOVE_UP: u16 = 1 << 8;
+
+/// Pack an intent's buttons into bit flags
+pub fn intent_to_bits(intent: &PlayerIntent) -> u16 {
+    let mut bits = 0;
+    for (held, bit) in [
+        (intent.move_left, MOVE_LEFT),
+        (intent.move_right, MOVE_RIGHT),
+        (intent.move_up, MOVE_UP),
+        (intent.move_down, MOVE_DOWN),
+        (intent.jump_pressed, JUMP_PRESSED),
+        (intent.jump_just_released, JUMP_JUST_RELEASED),
+        (intent.jump_just_pressed, JUMP_JUST_PRESSED),
+        (intent.swing_pressed, SWING_PRESSED),
+        (intent.swing_released, SWING_RELEASED),
+    ] {
+        if held {
+            bits |= bit;
+        }
+    }
+    bits
+}
+
+/// Unpack an intent packed by `intent_to_bits`
+pub fn intent_from_bits(bits: u16) -> PlayerIntent {
+    PlayerIntent {
+        move_left: bits & MOVE_LEFT != 0,
+        move_right: bits & MOVE_RIGHT != 0,
+        move_up: bits & MOVE_UP != 0,
+        move_down: bits & MOVE_DOWN != 0,
+        jump_pressed: bits & JUMP_PRESSED != 0,
+        jump_just_pressed: bits & JUMP_JUST_PRESSED != 0,
+        jump_just_released: bits & JUMP_JUST_RELEASED != 0,
+        swing_pressed: bits & SWING_PRESSED != 0,
+        swing_released: bits & SWING_RELEASED != 0,
+        ..Default::default()
+    }
+}
+
+/// While present, every fixed tick appends the player's intent to the recording
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct InputRecorder(pub InputRecording);
+
+/// While present, every fixed tick takes the player's intent from the recording instead of input
+/// Removed after the last recorded tick
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ReplayPlayback {
+    intents: Vec<PlayerIntent>,
+    next: usize,
+}
+
+impl ReplayPlayback {
+    pub fn new(recording: &InputRecording) -> Self {
+        Self {
+            intents: recording.intents().collect(),
+            next: 0,
+        }
+    }
+
+    /// Ticks left to play
+    pub fn remaining(&self) -> usize {
+        self.intents.len() - self.next
+    }
+}
+
+/// Request to replay a recording from the start of its level, handled at the start of the next frame
+/// The recording's level is reloaded, or loaded from `levels/<id>.json` when it is not the current
+/// one, and the player is reset to its spawn point with the recording's abilities
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub enum StartReplay {
+    File(PathBuf), // Recording written by save_recording
+    Recording(InputRecording),
+}
+
+/// Request to record every tick from the next frame on, starting from the current level and the
+/// player's abilities
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct StartRecording;
+
+/// Sent on the first tick after a replay runs out
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct ReplayFinished;
+
+/// Sent instead of starting a replay whose recording or level could not be loaded
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct ReplayFailed(pub ReplayError);
+
+/// Plugin that records and replays the player's per-tick intent
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        configure_game_sets(app);
+        app.add_event::<ReplayFinished>()
+            .add_event::<ReplayFailed>()
+            // Requests are handled before the frame's input and fixed ticks, so the first
+            // replayed or recorded tick runs in the same frame
+            .add_systems(
+                First,
+                (
+                    start_replay.run_if(resource_exists::<StartReplay>()),
+                    start_recording.run_if(resource_exists::<StartRecording>()),
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    play_back_input.run_if(resource_exists::<ReplayPlayback>()),
+                    record_input.run_if(resource_exists::<InputRecorder>()),
+                )
+                    .chain()
+                    .before(GameSet::Intent),
+            );
+    }
+}
+
+/// Load the requested recording and its level, put the level and player back at its start,
+/// and begin playback
+fn start_replay(
+    mut commands: Commands,
+    request: Res<StartReplay>,
+    current_level: Option<Res<CurrentLevel>>,
+    level_entities: Query<Entity, (LevelEntityFilter, Without<Player>)>,
+    player_query: Query<Entity, With<Player>>,
+    mut failed: EventWriter<ReplayFailed>,
+) {
+    commands.remove_resource::<StartReplay>();
+
+    let recording = match request.as_ref() {
+        StartReplay::File(path) => match load_recording(path) {
+            Ok(recording) => recording,
+            Err(e) => {
+                error!("Failed to start replay: {}", e);
+                failed.send(ReplayFailed(e));
+                return;
+            }
+        },
+        StartReplay::Recording(recording) => recording.clone(),
+    };
+
+    let level = match current_level.filter(|current| current.level_id == recording.level_id) {
+        Some(current) => current.level_data.clone(),
+        None => match load_level_from_file(&format!("levels/{}.json", recording.level_id)) {
+            Ok(level) => level,
+            Err(e) => {
+                let e = ReplayError::LevelError(recording.level_id.clone(), e.to_string());
+                error!("Failed to start replay: {}", e);
+                failed.send(ReplayFailed(e));
+                return;
+            }
+        },
+    };
+
+    // Restart the level from scratch, so the replay starts from the same world as the recording
+    for entity in level_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    spawn_level_entities(&mut commands, &level);
+
+    let spawn = Position::new(level.spawn_point.x, level.spawn_point.y);
+    for player in player_query.iter() {
+        commands
+            .entity(player)
+            .remove::<(SwingState, DropThrough)>()
+            .insert((
+                player_bundle(spawn, recording.abilities.clone()),
+                Teleported,
+            ));
+    }
+
+    commands.insert_resource(CurrentLevel {
+        level_id: level.id.clone(),
+        level_data: level,
+    });
+    commands.insert_resource(ReplayPlayback::new(&recording));
+}
+
+/// Begin a recording from the current level and the player's abilities
+fn start_recording(
+    mut commands: Commands,
+    current_level: Option<Res<CurrentLevel>>,
+    player_query: Query<&AbilitySet, With<Player>>,
+) {
+    commands.remove_resource::<StartRecording>();
+
+    let (Some(level), Ok(abilities)) = (current_level, player_query.get_single()) else {
+        warn!("Cannot record without a loaded level and a player");
+        return;
+    };
+    commands.insert_resource(InputRecorder(InputRecording::new(
+        level.level_id.clone(),
+        abilities.clone(),
+    )));
+}
+
+/// Overwrite the player's intent with the next recorded tick
+fn play_back_input(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    mut query: Query<&mut PlayerIntent, With<Player>>,
+    mut finished: EventWriter<ReplayFinished>,
+) {
+    let Some(recorded) = playback.intents.get(playback.next).copied() else {
+        for mut intent in query.iter_mut() {
+            *intent = PlayerIntent::default();
+        }
+        commands.remove_resource::<ReplayPlayback>();
+        finished.send(ReplayFinished);
+        return;
+    };
+
+    for mut intent in query.iter_mut() {
+        *intent = recorded;
+    }
+    playback.next += 1;
+}
+
+/// Append the intent this tick will read to the recording
+fn record_input(mut recorder: ResMut<InputRecorder>, query: Query<&PlayerIntent, With<Player>>) {
+    if let Ok(intent) = query.get_single() {
+        recorder.0.push(intent);
+    }
+}
+
+/// Write a recording to disk
+pub fn save_recording(path: &Path, recording: &InputRecording) -> Result<(), ReplayError> {
+    let display = path.display().to_string();
+    let json = serde_json::to_string(recording)
+        .map_err(|e| ReplayError::ParseError(display.clone(), e.to_string()))?;
+    fs::write(path, json).map_err(|e| ReplayError::IoError(display, e.to_string()))
+}
+
+/// Read a recording written by `save_recording`
+pub fn load_recording(path: &Path) -> Result<InputRecording, ReplayError> {
+    let display = path.display().to_string();
+    if !path.exists() {
+        return Err(ReplayError::FileNotFound(display));
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|e| ReplayError::IoError(display.clone(), e.to_string()))?;
+
+    // Check the version before the rest, so newer formats fail with a clear error
+    #[derive(Deserialize)]
+    struct Header {
+        version: u32,
+    }
+    let header: Header = serde_json::from_str(&contents)
+        .map_err(|e| ReplayError::ParseError(display.clone(), e.to_string()))?;
+    if header.version != REPLAY_VERSION {
+        return Err(ReplayError::UnsupportedVersion(header.version));
+    }
+
+    serde_json::from_str(&contents).map_err(|e| ReplayError::ParseError(display, e.to_string()))
+}
+
+/// Recording save, load and playback errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayError {
+    FileNotFound(String),
+    IoError(String, String),
+    ParseError(String, String),
+    UnsupportedVersion(u32),
+    LevelError(String, String), // Level the recording was made in, why it could not be loaded
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::FileNotFound(path) => write!(f, "Replay file not found: {}", path),
+            ReplayError::IoError(path, err) => {
+                write!(f, "IO error accessing replay file {}: {}", path, err)
+            }
+            ReplayError::ParseError(path, err) => {
+                write!(f, "Failed to parse replay file {}: {}", path, err)
+            }
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported replay version {} (expected {})",
+                version, REPLAY_VERSION
+            ),
+            ReplayError::LevelError(level, err) => {
+                write!(f, "Failed to load replay level {}: {}", level, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Position, Velocity};
+    use crate::enums::Ability;
+    use crate::harness::{SimulationHarness, block_level};
+    use crate::level::LevelData;
+    use tempfile::NamedTempFile;
+
+    fn intent(move_right: bool, jump_pressed: bool) -> PlayerIntent {
+        PlayerIntent {
+            move_right,
+            jump_pressed,
+            ..Default::default()
+        }
+    }
+
+    fn level() -> LevelData {
+        block_level(
+            "replay",
+            Position::new(0.0, 336.0),
+            &[(-200.0, 400.0, 1200.0, 64.0), (300.0, 300.0, 64.0, 100.0)],
+        )
+    }
+
+    #[test]
+    fn test_intent_bits_round_trip() {
+        for bits in 0..1 << 9 {
+            assert_eq!(intent_to_bits(&intent_from_bits(bits)), bits);
+        }
+        assert_eq!(intent_to_bits(&PlayerIntent::default()), 0);
+    }
+
+    #[test]
+    fn test_recording_is_run_length_encoded() {
+        let mut recording = InputRecording::new("test".to_string(), AbilitySet::new());
+        for _ in 0..30 {
+            recording.push(&intent(true, false));
+        }
+        recording.push(&intent(true, true));
+        recording.push(&intent(false, false));
+
+        assert_eq!(recording.inputs.len(), 3);
+        assert_eq!(recording.len(), 32);
+        let intents: Vec<_> = recording.intents().collect();
+        assert_eq!(intents[29], intent(true, false));
+        assert_eq!(intents[30], intent(true, true));
+        assert_eq!(intents[31], intent(false, false));
+    }
+
+    #[test]
+    fn test_save_and_load_recording() {
+        let mut recording = InputRecording::new(
+            "level_1".to_string(),
+            AbilitySet::from(vec![Ability::HighJump]),
+        );
+        recording.push(&intent(true, true));
+        let file = NamedTempFile::new().unwrap();
+
+        save_recording(file.path(), &recording).unwrap();
+        assert_eq!(load_recording(file.path()), Ok(recording));
+    }
+
+    #[test]
+    fn test_load_rejects_other_versions() {
+        let mut recording = InputRecording::new("level_1".to_string(), AbilitySet::new());
+        recording.version = REPLAY_VERSION + 1;
+        let file = NamedTempFile::new().unwrap();
+        save_recording(file.path(), &recording).unwrap();
+
+        assert_eq!(
+            load_recording(file.path()),
+            Err(ReplayError::UnsupportedVersion(REPLAY_VERSION + 1))
+        );
+        assert!(matches!(
+            load_recording(Path::new("no_such_replay.json")),
+            Err(ReplayError::FileNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_replay_reproduces_run_exactly() {
+        let mut original = SimulationHarness::new(level());
+        original.grant(Ability::HighJump).start_recording();
+        for tick in 0..240 {
+            let mut intent = intent(tick % 70 < 50, tick % 45 > 30);
+            if tick > 160 {
+                intent.move_x = -0.37; // Analog stick held part of the way left
+            }
+            original.step(intent);
+        }
+        let recording = original.recording().unwrap();
+        assert_eq!(recording.len(), 240);
+
+        let file = NamedTempFile::new().unwrap();
+        save_recording(file.path(), &recording).unwrap();
+        let loaded = load_recording(file.path()).unwrap();
+
+        let mut replay = SimulationHarness::from_recording(level(), &loaded).unwrap();
+        assert_eq!(replay.player_position(), original.player_position());
+        assert_eq!(
+            replay.player_get::<Velocity>(),
+            original.player_get::<Velocity>()
+        );
+
+        // The first tick past the end stops the replay
+        replay.step(PlayerIntent::default());
+        assert_eq!(replay.world().get_resource::<ReplayPlayback>(), None);
+        assert_eq!(
+            replay
+                .world_mut()
+                .resource_mut::<Events<ReplayFinished>>()
+                .drain()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_replay_of_unloadable_level_or_recording_fails() {
+        let recording = InputRecording::new("no_such_level".to_string(), AbilitySet::new());
+        assert!(matches!(
+            SimulationHarness::from_recording(level(), &recording).err(),
+            Some(ReplayError::LevelError(level, _)) if level == "no_such_level"
+        ));
+
+        let mut harness = SimulationHarness::new(level());
+        harness
+            .world_mut()
+            .insert_resource(StartReplay::File("no_such_replay.json".into()));
+        harness.step(PlayerIntent::default());
+        let failures: Vec<_> = harness
+            .world_mut()
+            .resource_mut::<Events<ReplayFailed>>()
+            .drain()
+            .collect();
+        assert!(matches!(
+            failures.as_slice(),
+            [ReplayFailed(ReplayError::FileNotFound(_))]
+        ));
+        assert_eq!(harness.world().get_resource::<ReplayPlayback>(), None);
+    }
+
+    #[test]
+    fn test_replay_restarts_level_from_file_recording() {
+        let mut original = SimulationHarness::new(level());
+        original.start_recording();
+        original.step_n(60, intent(true, false));
+        let file = NamedTempFile::new().unwrap();
+        save_recording(file.path(), &original.recording().unwrap()).unwrap();
+
+        // Wander off first; starting the replay puts the player back at the spawn point
+        let mut replay = SimulationHarness::new(level());
+        replay.step_n(30, intent(false, true));
+        replay
+            .world_mut()
+            .insert_resource(StartReplay::File(file.path().to_path_buf()));
+        replay.step_n(60, PlayerIntent::default());
+
+        assert_eq!(replay.player_position(), original.player_position());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::plugins::render_transform::WorldToRender;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+/// Stages of a game frame, shared by every plugin so their systems run in a fixed order
+///
+/// Input runs in PreUpdate once per frame and latches device state into PlayerIntent.
+/// Intent, Movement, Physics and PostPhysics run in that order on every FixedUpdate tick.
+/// Presentation runs in PostUpdate, before transforms are propagated for rendering.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    Input,        // Read devices into PlayerIntent
+    Intent,       // Turn intent into state changes like clinging or attaching to a swing
+    Movement,     // Write velocities from intent and movement state
+    Physics,      // Integrate velocities and resolve collisions
+    PostPhysics,  // React to the resolved positions: contacts, grounded and wall state
+    Presentation, // Animation, effects, camera and render transforms
+}
+
+/// Order the game sets in their schedules
+/// Every plugin that uses the sets calls this, so any combination of plugins is ordered
+pub(crate) fn configure_game_sets(app: &mut App) {
+    app.configure_sets(PreUpdate, GameSet::Input.after(InputSystem))
+        .configure_sets(
+            FixedUpdate,
+            (
+                GameSet::Intent,
+                GameSet::Movement,
+                GameSet::Physics,
+                GameSet::PostPhysics,
+            )
+                .chain(),
+        )
+        .configure_sets(
+            PostUpdate,
+            (
+                GameSet::Presentation.before(TransformSystem::TransformPropagate),
+                WorldToRender.in_set(GameSet::Presentation),
+            ),
+        );
+}
@@ -1,163 +1,373 @@
 use crate::components::{
-    AbilitySet, GroundedState, Player, PlayerIntent, Position, SwingPoint, SwingState, Velocity,
+    AbilitySet, Collider, CollisionLayers, GeometryShape, GroundedState, LevelGeometry, Player,
+    PlayerIntent, Position, RopeWrap, SwingPoint, SwingState, SwingTarget, Velocity,
 };
-use crate::enums::Ability;
+use crate::enums::{Ability, BoxCorner};
+use crate::plugins::broadphase::SpatialHash;
+use crate::plugins::physics::CollisionContacts;
+use crate::plugins::schedule::{GameSet, configure_game_sets};
+use crate::tuning::MovementTuning;
 use bevy::prelude::*;
 
 /// Physics constants for swing mechanics
-pub const SWING_DAMPING: f32 = 0.98; // Angular velocity damping per frame
-pub const SWING_INPUT_TORQUE: f32 = 2.0; // Torque applied by player input
 pub const SWING_RANGE: f32 = 100.0; // Default range for swing points
+pub const MIN_FREE_ROPE: f32 = 4.0; // Rope that must be left past a corner for it to wrap
+pub const MIN_ROPE_LENGTH: f32 = 32.0; // Default shortest rope a swing point reels in to
+pub const MAX_ROPE_LENGTH: f32 = 160.0; // Default longest rope a swing point lets out
 
 /// Plugin for swing mechanic
 pub struct SwingPlugin;
 
 impl Plugin for SwingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                detect_swing_points_system,
-                attach_to_swing_system,
-                update_swing_physics_system,
-                release_swing_system,
-            )
-                .chain(),
-        );
+        configure_game_sets(app);
+        app.init_resource::<MovementTuning>()
+            .init_resource::<SpatialHash>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    (detect_swing_points_system, attach_to_swing_system)
+                        .chain()
+                        .in_set(GameSet::Intent),
+                    (update_swing_physics_system, release_swing_system)
+                        .chain()
+                        .in_set(GameSet::Movement),
+                    sync_swing_after_collisions.in_set(GameSet::PostPhysics),
+                ),
+            );
     }
 }
 
-/// Detect nearby swing points and store the closest one
+/// Center of a body's collider, where the rope is tied
+pub fn body_center(position: &Position, collider: &Collider) -> Vec2 {
+    Vec2::new(
+        position.x + collider.offset_x + collider.width / 2.0,
+        position.y + collider.offset_y + collider.height / 2.0,
+    )
+}
+
+/// Angular acceleration of a pendulum whose bob is at `angle` from the anchor (y down)
+/// Zero hanging straight down at PI / 2, pulling back toward it from either side
+pub fn pendulum_angular_acceleration(gravity: f32, rope_length: f32, angle: f32) -> f32 {
+    (gravity / rope_length) * angle.cos()
+}
+
+/// Find each player's nearest swing point in range
 fn detect_swing_points_system(
-    player_query: Query<&Position, With<Player>>,
-    swing_point_query: Query<(&Position, &SwingPoint)>,
-    mut nearest_swing_point: Local<Option<(Vec2, f32)>>, // (position, distance)
+    mut player_query: Query<(&Position, &Collider, &mut SwingTarget), With<Player>>,
+    swing_point_query: Query<(Entity, &Position, &SwingPoint)>,
 ) {
-    *nearest_swing_point = None;
-
-    for player_pos in player_query.iter() {
-        let player_position = Vec2::new(player_pos.x, player_pos.y);
-        let mut closest_distance = f32::MAX;
-        let mut closest_point = None;
+    for (player_pos, collider, mut target) in player_query.iter_mut() {
+        let player_center = body_center(player_pos, collider);
+        let mut nearest = SwingTarget::default();
 
-        for (swing_pos, swing_point) in swing_point_query.iter() {
+        for (point, swing_pos, swing_point) in swing_point_query.iter() {
             let swing_position = Vec2::new(swing_pos.x, swing_pos.y);
-            let distance = player_position.distance(swing_position);
+            let distance = player_center.distance(swing_position);
 
             // Check if within range and closer than previous closest
-            if distance <= swing_point.range && distance < closest_distance {
-                closest_distance = distance;
-                closest_point = Some((swing_position, distance));
+            if distance <= swing_point.range
+                && (nearest.point.is_none() || distance < nearest.distance)
+            {
+                nearest = SwingTarget {
+                    point: Some(point),
+                    anchor: swing_position,
+                    distance,
+                };
             }
         }
 
-        *nearest_swing_point = closest_point;
+        *target = nearest;
     }
 }
 
-/// Attach to swing point when conditions are met
+/// Attach to the targeted swing point while swing is held in the air
+/// The rope keeps the part of the player's velocity that goes around the anchor
 #[allow(clippy::type_complexity)]
 fn attach_to_swing_system(
     mut commands: Commands,
-    mut player_query: Query<
+    player_query: Query<
         (
             Entity,
             &Position,
+            &Collider,
+            &Velocity,
             &AbilitySet,
             &PlayerIntent,
             &GroundedState,
+            &SwingTarget,
         ),
         (With<Player>, Without<SwingState>),
     >,
-    nearest_swing_point: Local<Option<(Vec2, f32)>>,
+    swing_point_query: Query<&SwingPoint>,
 ) {
-    for (entity, _player_pos, ability_set, intent, grounded) in player_query.iter_mut() {
-        // Can only attach if:
-        // 1. Swing ability is unlocked
-        // 2. Player is within range of a swing point
-        // 3. Player presses the swing key (we'll use 'E' key)
-        // 4. Player is not grounded (can't swing from ground)
-
-        if !ability_set.has(Ability::Swing) {
+    for (entity, position, collider, velocity, ability_set, intent, grounded, target) in
+        player_query.iter()
+    {
+        if !ability_set.has(Ability::Swing) || grounded.is_grounded || !intent.swing_pressed {
             continue;
         }
-
-        if grounded.is_grounded {
+        let Some(swing_point) = target
+            .point
+            .and_then(|point| swing_point_query.get(point).ok())
+        else {
+            continue;
+        };
+        if target.distance <= 0.0 {
             continue;
         }
 
-        // Check if there's a nearby swing point
-        if let Some((swing_position, distance)) = *nearest_swing_point {
-            // For now, we'll use jump_pressed as swing key (will be changed later)
-            // In a real implementation, we'd add a separate swing_pressed field to PlayerIntent
-            if intent.jump_pressed {
-                // Attach to swing point
-                let rope_length = distance;
-
-                commands.entity(entity).insert(SwingState {
-                    anchor_point: swing_position,
-                    rope_length,
-                    angular_velocity: 0.0,
-                });
-            }
+        let offset = body_center(position, collider) - target.anchor;
+        let tangent = Vec2::new(-offset.y, offset.x) / target.distance;
+        let tangential_speed = Vec2::new(velocity.x, velocity.y).dot(tangent);
+
+        commands.entity(entity).insert(
+            SwingState::new(
+                target.anchor,
+                target.distance,
+                tangential_speed / target.distance,
+            )
+            .with_length_limits(swing_point.min_length, swing_point.max_length),
+        );
+    }
+}
+
+/// Corners of a piece of geometry that a rope can bend around
+/// Slopes have no corner opposite their right angle, where the hypotenuse runs
+pub fn rope_corners(geometry: &LevelGeometry) -> Vec<Vec2> {
+    let (left, right) = (geometry.x, geometry.x + geometry.width);
+    let (top, bottom) = (geometry.y, geometry.y + geometry.height);
+    let corners = [
+        (BoxCorner::TopLeft, Vec2::new(left, top)),
+        (BoxCorner::TopRight, Vec2::new(right, top)),
+        (BoxCorner::BottomLeft, Vec2::new(left, bottom)),
+        (BoxCorner::BottomRight, Vec2::new(right, bottom)),
+    ];
+    let missing = match geometry.shape {
+        GeometryShape::Box => None,
+        GeometryShape::Triangle(corner) | GeometryShape::Ramp(corner) => Some(match corner {
+            BoxCorner::TopLeft => BoxCorner::BottomRight,
+            BoxCorner::TopRight => BoxCorner::BottomLeft,
+            BoxCorner::BottomLeft => BoxCorner::TopRight,
+            BoxCorner::BottomRight => BoxCorner::TopLeft,
+        }),
+    };
+
+    corners
+        .into_iter()
+        .filter(|(corner, _)| Some(*corner) != missing)
+        .map(|(_, point)| point)
+        .collect()
+}
+
+/// Which side of the line from `from` through `to` a point lies on, by sign
+fn side_of(from: Vec2, to: Vec2, point: Vec2) -> f32 {
+    (to - from).perp_dot(point - to)
+}
+
+/// First corner the rope touches while its free end sweeps from `from` to `to` around `pivot`
+/// A corner is touched when it lies inside the triangle the rope sweeps; the rope reaches the
+/// one at the smallest angle first, and the nearest of several in line
+pub fn first_rope_contact(
+    pivot: Vec2,
+    from: Vec2,
+    to: Vec2,
+    corners: impl IntoIterator<Item = Vec2>,
+) -> Option<Vec2> {
+    let sweep = (from - pivot).perp_dot(to - pivot);
+    if sweep == 0.0 {
+        return None;
+    }
+    let length = from.distance(pivot).max(to.distance(pivot));
+
+    corners
+        .into_iter()
+        .filter(|corner| corner.distance(pivot) < length - MIN_FREE_ROPE)
+        .filter(|corner| {
+            let inside = |a: Vec2, b: Vec2| (b - a).perp_dot(*corner - a) * sweep > 0.0;
+            inside(pivot, from) && inside(from, to) && inside(to, pivot)
+        })
+        .map(|corner| {
+            let angle = (from - pivot).angle_between(corner - pivot).abs();
+            (corner, angle, corner.distance(pivot))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+        .map(|(corner, _, _)| corner)
+}
+
+/// Unwrap the last corner once the player swings back past it, otherwise wrap the first
+/// corner the rope sweeps into
+/// Returns where the player's center should move to on the rope's new circle. The player
+/// keeps their speed around the new pivot.
+pub fn wrap_rope(swing: &mut SwingState, from: Vec2, to: Vec2, corners: &[Vec2]) -> Vec2 {
+    let length = swing.free_length();
+
+    if let Some(wrap) = swing.wraps.last().copied() {
+        let previous = swing
+            .wraps
+            .iter()
+            .rev()
+            .nth(1)
+            .map_or(swing.anchor_point, |wrap| wrap.corner);
+        if side_of(previous, wrap.corner, to) * wrap.side < 0.0 {
+            swing.wraps.pop();
+            let unwrapped_length = swing.free_length();
+            swing.angular_velocity *= length / unwrapped_length;
+            return previous + (to - previous).normalize_or_zero() * unwrapped_length;
         }
     }
+
+    let pivot = swing.pivot();
+    if let Some(corner) = first_rope_contact(pivot, from, to, corners.iter().copied()) {
+        swing.wraps.push(RopeWrap {
+            corner,
+            side: side_of(pivot, corner, to).signum(),
+        });
+        let wrapped_length = swing.free_length();
+        swing.angular_velocity *= length / wrapped_length;
+        return corner + (to - corner).normalize_or_zero() * wrapped_length;
+    }
+
+    to
+}
+
+/// Reel the rope in (negative) or out (positive) by up to `amount`, within its limits
+/// Angular momentum around the pivot is kept, so reeling in speeds the swing up the way
+/// pumping a real pendulum does. Returns the new free length.
+pub fn reel_rope(swing: &mut SwingState, amount: f32) -> f32 {
+    let length = swing.free_length();
+    let wrapped = swing.rope_length - length;
+    swing.rope_length = (swing.rope_length + amount)
+        .clamp(swing.min_length, swing.max_length)
+        .max(wrapped + MIN_FREE_ROPE);
+
+    let reeled = swing.free_length();
+    swing.angular_velocity *= (length / reeled).powi(2);
+    reeled
 }
 
 /// Update swing physics using pendulum mechanics
+/// The rope swings the player around its pivot and wraps around corners it sweeps into, and
+/// the player moves there through the usual collision resolution
+#[allow(clippy::type_complexity)]
 fn update_swing_physics_system(
-    mut query: Query<(&mut Position, &mut Velocity, &mut SwingState, &PlayerIntent), With<Player>>,
-    time: Res<Time>,
+    mut query: Query<
+        (
+            &Position,
+            &Collider,
+            &mut Velocity,
+            &mut SwingState,
+            &PlayerIntent,
+            Option<&CollisionLayers>,
+        ),
+        With<Player>,
+    >,
+    geometry_query: Query<&LevelGeometry>,
+    spatial_hash: Res<SpatialHash>,
+    time: Res<Time<Fixed>>,
+    tuning: Res<MovementTuning>,
 ) {
     let delta_time = time.delta_seconds();
+    if delta_time <= 0.0 {
+        return;
+    }
 
-    for (mut position, mut velocity, mut swing_state, input) in query.iter_mut() {
-        // Calculate current angle from anchor point
-        let dx = position.x - swing_state.anchor_point.x;
-        let dy = position.y - swing_state.anchor_point.y;
-        let angle = dy.atan2(dx);
-
-        // Apply pendulum physics: angular acceleration = (g / L) * sin(θ)
-        let gravity = 980.0; // pixels per second squared
-        let angular_acceleration = (gravity / swing_state.rope_length) * angle.sin();
-        swing_state.angular_velocity += angular_acceleration * delta_time;
+    for (position, collider, mut velocity, mut swing_state, input, layers) in query.iter_mut() {
+        let pivot = swing_state.pivot();
+        let mut length = swing_state.free_length();
 
-        // Apply player input as torque
-        if input.move_left {
-            swing_state.angular_velocity -= SWING_INPUT_TORQUE * delta_time;
-        }
-        if input.move_right {
-            swing_state.angular_velocity += SWING_INPUT_TORQUE * delta_time;
+        // Up reels the rope in and down lets it out
+        let reel = match (input.move_up, input.move_down) {
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0,
+        };
+        if reel != 0.0 {
+            length = reel_rope(
+                &mut swing_state,
+                reel * tuning.swing_reel_speed * delta_time,
+            );
         }
 
+        // Calculate current angle from the pivot
+        let center = body_center(position, collider);
+        let offset = center - pivot;
+        let angle = offset.y.atan2(offset.x);
+
+        swing_state.angular_velocity +=
+            pendulum_angular_acceleration(tuning.gravity, length, angle) * delta_time;
+
+        // Apply player input as torque, pushing the swing toward the held direction
+        // (increasing angle sweeps leftward through the bottom of the arc)
+        swing_state.angular_velocity -= input.horizontal() * tuning.swing_input_torque * delta_time;
+
         // Apply damping
-        swing_state.angular_velocity *= SWING_DAMPING;
+        swing_state.angular_velocity *= tuning.swing_damping;
 
-        // Update angle
+        // Calculate new position on arc
         let new_angle = angle + swing_state.angular_velocity * delta_time;
+        let swung = pivot + length * Vec2::new(new_angle.cos(), new_angle.sin());
+
+        // Bend the rope around geometry it swept into, or straighten it again
+        let layers = layers.copied().unwrap_or_default();
+        let min = pivot.min(center).min(swung);
+        let max = pivot.max(center).max(swung);
+        let corners: Vec<Vec2> = spatial_hash
+            .query(min, max)
+            .into_iter()
+            .filter_map(|entity| geometry_query.get(entity).ok())
+            .filter(|geometry| !geometry.one_way && layers.interacts_with(&geometry.layers))
+            .flat_map(rope_corners)
+            .collect();
+        let target = wrap_rope(&mut swing_state, center, swung, &corners);
+
+        // Collision resolution moves the player there and stops them against geometry
+        velocity.x = (target.x - center.x) / delta_time;
+        velocity.y = (target.y - center.y) / delta_time;
+    }
+}
 
-        // Calculate new position on arc
-        position.x = swing_state.anchor_point.x + swing_state.rope_length * new_angle.cos();
-        position.y = swing_state.anchor_point.y + swing_state.rope_length * new_angle.sin();
+/// Match the swing to where collisions left the player
+/// Whatever part of the swing went into geometry is lost
+#[allow(clippy::type_complexity)]
+fn sync_swing_after_collisions(
+    mut query: Query<
+        (
+            &Position,
+            &Collider,
+            &Velocity,
+            &mut SwingState,
+            &CollisionContacts,
+        ),
+        With<Player>,
+    >,
+) {
+    for (position, collider, velocity, mut swing_state, contacts) in query.iter_mut() {
+        if contacts.contacts.is_empty() {
+            continue;
+        }
 
-        // Calculate tangential velocity for release
-        velocity.x = -swing_state.rope_length * swing_state.angular_velocity * new_angle.sin();
-        velocity.y = swing_state.rope_length * swing_state.angular_velocity * new_angle.cos();
+        let offset = body_center(position, collider) - swing_state.pivot();
+        let distance = offset.length();
+        if distance <= 0.0 {
+            continue;
+        }
+        let tangent = Vec2::new(-offset.y, offset.x) / distance;
+        swing_state.angular_velocity =
+            Vec2::new(velocity.x, velocity.y).dot(tangent) / swing_state.free_length();
     }
 }
 
-/// Release from swing point when key is released
+/// Let go of the rope when swing is released, keeping the swing's velocity
+#[allow(clippy::type_complexity)]
 fn release_swing_system(
     mut commands: Commands,
-    query: Query<(Entity, &PlayerIntent, &SwingState), With<Player>>,
+    query: Query<(Entity, &PlayerIntent), (With<Player>, With<SwingState>)>,
 ) {
-    for (entity, intent, _swing_state) in query.iter() {
-        // Release when jump key is released
-        // In a real implementation, we'd check for swing key release
-        if intent.jump_just_released {
-            // Remove SwingState component to exit swing mode
-            commands.entity(entity).remove::<SwingState>();
+    for (entity, intent) in query.iter() {
+        if intent.swing_released {
             // Velocity is already set by update_swing_physics_system
+            commands.entity(entity).remove::<SwingState>();
         }
     }
 }
@@ -165,174 +375,542 @@ fn release_swing_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::harness::{SimulationHarness, block_level};
+    use crate::level::SwingPointData;
+
+    fn tuning() -> MovementTuning {
+        MovementTuning::default()
+    }
 
     #[test]
-    fn test_swing_point_detection_within_range() {
-        let player_pos = Position::new(100.0, 100.0);
-        let swing_pos = Position::new(150.0, 100.0);
-        let swing_point = SwingPoint { range: 100.0 };
+    fn test_pendulum_angular_acceleration() {
+        let gravity = tuning().gravity;
+        let rope_length = 100.0;
 
-        let player_position = Vec2::new(player_pos.x, player_pos.y);
-        let swing_position = Vec2::new(swing_pos.x, swing_pos.y);
-        let distance = player_position.distance(swing_position);
+        // Hanging straight down there is nothing to swing back from
+        let hanging =
+            pendulum_angular_acceleration(gravity, rope_length, std::f32::consts::PI / 2.0);
+        assert!(hanging.abs() < 1e-3);
 
-        assert!(
-            distance <= swing_point.range,
-            "Player should be within swing range"
+        // Held out level to the right, gravity pulls toward the bottom at full strength
+        let level = pendulum_angular_acceleration(gravity, rope_length, 0.0);
+        assert!((level - gravity / rope_length).abs() < 1e-3);
+
+        // Out to the left it pulls the other way
+        let left = pendulum_angular_acceleration(gravity, rope_length, std::f32::consts::PI);
+        assert!((left + gravity / rope_length).abs() < 1e-3);
+    }
+
+    /// Floor at y = 400 with a swing point hanging above and ahead of the spawn
+    fn swing_harness() -> SimulationHarness {
+        let mut level = block_level(
+            "swing",
+            Position::new(-100.0, 336.0),
+            &[(-400.0, 400.0, 1200.0, 64.0)],
         );
-        assert_eq!(distance, 50.0, "Distance should be 50 pixels");
+        level.swing_points.push(SwingPointData {
+            x: 100.0,
+            y: 250.0,
+            min_length: Some(40.0),
+            max_length: Some(140.0),
+        });
+        SimulationHarness::new(level)
     }
 
-    #[test]
-    fn test_swing_point_detection_out_of_range() {
-        let player_pos = Position::new(100.0, 100.0);
-        let swing_pos = Position::new(300.0, 100.0);
-        let swing_point = SwingPoint { range: 100.0 };
+    fn run_right() -> PlayerIntent {
+        PlayerIntent {
+            move_right: true,
+            ..Default::default()
+        }
+    }
 
-        let player_position = Vec2::new(player_pos.x, player_pos.y);
-        let swing_position = Vec2::new(swing_pos.x, swing_pos.y);
-        let distance = player_position.distance(swing_position);
+    fn jump_and_swing() -> PlayerIntent {
+        PlayerIntent {
+            jump_pressed: true,
+            swing_pressed: true,
+            ..run_right()
+        }
+    }
 
-        assert!(
-            distance > swing_point.range,
-            "Player should be out of swing range"
-        );
+    /// Run until the swing point is nearly in reach, then jump holding swing
+    fn jump_for_swing_point(harness: &mut SimulationHarness) {
+        harness
+            .step_n(5, PlayerIntent::default())
+            .step_n(20, run_right())
+            .step_n(15, jump_and_swing());
+    }
+
+    fn player_center(harness: &SimulationHarness) -> Vec2 {
+        body_center(
+            &harness.player_position(),
+            harness.player_get::<Collider>().unwrap(),
+        )
     }
 
     #[test]
-    fn test_swing_attachment_requires_ability() {
-        let ability_set = AbilitySet::new(); // No swing ability
+    fn test_attach_swing_and_release() {
+        let mut harness = swing_harness();
+        harness.grant(Ability::Swing);
+        jump_for_swing_point(&mut harness);
+
+        let swing = harness
+            .player_get::<SwingState>()
+            .cloned()
+            .expect("Player should be swinging");
+        assert_eq!(swing.anchor_point, Vec2::new(100.0, 250.0));
+        assert!(swing.rope_length <= SWING_RANGE);
+        assert_eq!((swing.min_length, swing.max_length), (40.0, 140.0));
+
+        // The rope holds the player at its length while swinging
+        harness.step_n(
+            20,
+            PlayerIntent {
+                swing_pressed: true,
+                ..Default::default()
+            },
+        );
+        assert!(harness.player_get::<SwingState>().is_some());
+        let distance = player_center(&harness).distance(swing.anchor_point);
         assert!(
-            !ability_set.has(Ability::Swing),
-            "Should not have swing ability"
+            (distance - swing.rope_length).abs() < 0.01,
+            "Rope of {} stretched to {distance}",
+            swing.rope_length
+        );
+
+        // Letting go flies off with the swing's velocity, give or take its last tick
+        let swing_velocity = *harness.player_get::<Velocity>().unwrap();
+        let swing_velocity = Vec2::new(swing_velocity.x, swing_velocity.y);
+        harness.step(PlayerIntent::default());
+        assert!(harness.player_get::<SwingState>().is_none());
+        let velocity = harness.player_get::<Velocity>().unwrap();
+        let velocity = Vec2::new(velocity.x, velocity.y);
+        assert!(
+            (velocity - swing_velocity).length() < 0.1 * swing_velocity.length(),
+            "Released at {swing_velocity:?}, now {velocity:?}"
         );
     }
 
     #[test]
-    fn test_swing_attachment_with_ability() {
-        let mut ability_set = AbilitySet::new();
-        ability_set.add(Ability::Swing);
-        assert!(ability_set.has(Ability::Swing), "Should have swing ability");
+    fn test_jump_alone_does_not_attach() {
+        let mut harness = swing_harness();
+        harness.grant(Ability::Swing);
+        harness
+            .step_n(5, PlayerIntent::default())
+            .step_n(20, run_right())
+            .step_n(
+                15,
+                PlayerIntent {
+                    jump_pressed: true,
+                    ..run_right()
+                },
+            );
+
+        assert!(harness.player_get::<SwingState>().is_none());
+        assert!(harness.player_get::<SwingTarget>().unwrap().point.is_some());
     }
 
     #[test]
-    fn test_pendulum_angular_acceleration() {
-        let gravity = 980.0;
-        let rope_length = 100.0;
-        let angle = std::f32::consts::PI / 4.0; // 45 degrees
+    fn test_no_attach_without_swing_ability() {
+        let mut harness = swing_harness();
+        jump_for_swing_point(&mut harness);
 
-        let angular_acceleration = (gravity / rope_length) * angle.sin();
+        assert!(harness.player_get::<SwingState>().is_none());
+    }
 
-        // For 45 degrees, sin(45°) ≈ 0.707
-        let expected = (gravity / rope_length) * 0.707;
-        assert!(
-            (angular_acceleration - expected).abs() < 0.1,
-            "Angular acceleration should match pendulum formula"
+    #[test]
+    fn test_swing_point_out_of_reach_from_ground() {
+        let mut harness = swing_harness();
+        harness.grant(Ability::Swing);
+        harness.step_n(
+            5,
+            PlayerIntent {
+                swing_pressed: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            *harness.player_get::<SwingTarget>().unwrap(),
+            SwingTarget::default()
         );
+        assert!(harness.player_get::<SwingState>().is_none());
+    }
+
+    /// Player standing at x = -100 on the swing level's floor, with extra swing points
+    fn standing_harness(points: &[(f32, f32)]) -> SimulationHarness {
+        let mut level = block_level(
+            "standing",
+            Position::new(-100.0, 336.0),
+            &[(-400.0, 400.0, 1200.0, 64.0)],
+        );
+        level
+            .swing_points
+            .extend(points.iter().map(|&(x, y)| SwingPointData {
+                x,
+                y,
+                min_length: None,
+                max_length: None,
+            }));
+        SimulationHarness::new(level)
+    }
+
+    fn swing_point_at(harness: &mut SimulationHarness, anchor: Vec2) -> Entity {
+        harness
+            .entities_with::<SwingPoint>()
+            .into_iter()
+            .find(|&point| {
+                let position = harness.get::<Position>(point).unwrap();
+                Vec2::new(position.x, position.y) == anchor
+            })
+            .unwrap()
     }
 
     #[test]
-    fn test_swing_damping_reduces_velocity() {
-        let mut angular_velocity = 10.0;
-        let initial_velocity = angular_velocity;
+    fn test_nearest_swing_point_in_range_is_targeted() {
+        // The player's center is at (-84, 368)
+        let mut harness = standing_harness(&[(-44.0, 300.0), (-84.0, 300.0), (200.0, 300.0)]);
+        harness.step_n(3, PlayerIntent::default());
+
+        let nearest = swing_point_at(&mut harness, Vec2::new(-84.0, 300.0));
+        let target = *harness.player_get::<SwingTarget>().unwrap();
+        assert_eq!(target.point, Some(nearest));
+        assert_eq!(target.anchor, Vec2::new(-84.0, 300.0));
+        assert!((target.distance - 68.0).abs() < 0.01);
+    }
 
-        angular_velocity *= SWING_DAMPING;
+    #[test]
+    fn test_attaches_only_once_airborne() {
+        let mut harness = standing_harness(&[(-84.0, 300.0)]);
+        harness.grant(Ability::Swing);
+
+        // In reach but standing on the floor, holding swing does nothing
+        harness
+            .step_n(3, PlayerIntent::default())
+            .step_n(5, hold_swing());
+        assert!(harness.player_get::<SwingTarget>().unwrap().point.is_some());
+        assert!(harness.player_get::<GroundedState>().unwrap().is_grounded);
+        assert!(harness.player_get::<SwingState>().is_none());
+
+        // Leaving the ground while still holding it attaches
+        harness.step_n(
+            3,
+            PlayerIntent {
+                jump_pressed: true,
+                ..hold_swing()
+            },
+        );
+        let swing = harness
+            .player_get::<SwingState>()
+            .expect("Player should attach once airborne");
+        assert_eq!(swing.anchor_point, Vec2::new(-84.0, 300.0));
+    }
 
-        assert!(
-            angular_velocity < initial_velocity,
-            "Damping should reduce angular velocity"
+    #[test]
+    fn test_swing_damping_loses_energy() {
+        let start = Vec2::new(100.0, 0.0);
+        let mut damped = hanging_harness(Vec2::ZERO, start, &[]);
+        let mut undamped = hanging_harness(Vec2::ZERO, start, &[]);
+        undamped
+            .world_mut()
+            .resource_mut::<MovementTuning>()
+            .swing_damping = 1.0;
+        damped.step_n(30, hold_swing());
+        undamped.step_n(30, hold_swing());
+
+        assert!(swing_energy(&damped) < swing_energy(&undamped));
+    }
+
+    #[test]
+    fn test_swing_input_pushes_toward_held_direction() {
+        let hold = |direction: PlayerIntent| PlayerIntent {
+            swing_pressed: true,
+            ..direction
+        };
+        let mut right = hanging_harness(Vec2::ZERO, Vec2::new(0.0, 100.0), &[]);
+        let mut left = hanging_harness(Vec2::ZERO, Vec2::new(0.0, 100.0), &[]);
+
+        right.step_n(
+            10,
+            hold(PlayerIntent {
+                move_right: true,
+                ..Default::default()
+            }),
         );
-        assert!(
-            angular_velocity > 0.0,
-            "Damping should not reverse direction"
+        left.step_n(
+            10,
+            hold(PlayerIntent {
+                move_left: true,
+                ..Default::default()
+            }),
         );
+
+        assert!(player_center(&right).x > 1.0);
+        assert!(right.player_get::<Velocity>().unwrap().x > 0.0);
+        assert!(player_center(&left).x < -1.0);
+        assert!(left.player_get::<Velocity>().unwrap().x < 0.0);
     }
 
     #[test]
-    fn test_swing_input_affects_angular_velocity() {
-        let mut angular_velocity = 0.0;
-        let delta_time = 1.0 / 60.0;
+    fn test_swing_moves_along_arc() {
+        let mut harness = hanging_harness(Vec2::ZERO, Vec2::new(100.0, 0.0), &[]);
+
+        for _ in 0..40 {
+            harness.step(hold_swing());
+
+            // The player stays on the rope's circle, moving around it rather than along it
+            let offset = player_center(&harness);
+            assert!((offset.length() - 100.0).abs() < 0.01, "Player at {offset}");
+            let velocity = harness.player_get::<Velocity>().unwrap();
+            let velocity = Vec2::new(velocity.x, velocity.y);
+            let radial = velocity.dot(offset.normalize());
+            assert!(
+                radial.abs() < 0.1 * velocity.length(),
+                "Velocity {velocity} points off the arc at {offset}"
+            );
+        }
+    }
 
-        // Apply right input
-        angular_velocity += SWING_INPUT_TORQUE * delta_time;
+    #[test]
+    fn test_rope_corners_skip_slope_hypotenuse() {
+        let block = LevelGeometry::new(0.0, 0.0, 10.0, 20.0);
+        assert_eq!(rope_corners(&block).len(), 4);
 
-        assert!(
-            angular_velocity > 0.0,
-            "Right input should increase angular velocity"
+        let slope = LevelGeometry {
+            shape: GeometryShape::Triangle(BoxCorner::BottomLeft),
+            ..block
+        };
+        let corners = rope_corners(&slope);
+        assert_eq!(corners.len(), 3);
+        assert!(!corners.contains(&Vec2::new(10.0, 0.0)));
+    }
+
+    #[test]
+    fn test_rope_wraps_and_unwraps_around_corner() {
+        let anchor = Vec2::ZERO;
+        let corner = Vec2::new(50.0, 10.0);
+        let mut swing = SwingState::new(anchor, 100.0, 1.0);
+
+        // Swinging down from level, the rope sweeps into the corner and bends around it
+        let from = Vec2::new(100.0, 0.0);
+        let to = 100.0 * Vec2::new(0.3f32.cos(), 0.3f32.sin());
+        let target = wrap_rope(&mut swing, from, to, &[corner]);
+        assert_eq!(swing.pivot(), corner);
+        let free = 100.0 - corner.length();
+        assert!((swing.free_length() - free).abs() < 1e-3);
+        assert!((target.distance(corner) - free).abs() < 1e-3);
+        assert!((swing.angular_velocity - 100.0 / free).abs() < 1e-3);
+
+        // Still on the same side of the bend, the rope stays wrapped
+        let target = wrap_rope(&mut swing, target, target + Vec2::new(-5.0, 5.0), &[corner]);
+        assert_eq!(swing.wraps.len(), 1);
+
+        // Swinging back up past the line through the anchor and the corner straightens it
+        let back = Vec2::new(95.0, 5.0);
+        let target = wrap_rope(&mut swing, target, back, &[corner]);
+        assert!(swing.wraps.is_empty());
+        assert!((target.length() - 100.0).abs() < 1e-3);
+        assert!((swing.angular_velocity - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_rope_ignores_corners_it_does_not_sweep() {
+        let pivot = Vec2::ZERO;
+        let from = Vec2::new(100.0, 0.0);
+        let to = Vec2::new(90.0, 40.0);
+
+        // Behind the rope, beyond its end, and right at the player
+        let corners = [
+            Vec2::new(50.0, -10.0),
+            Vec2::new(120.0, 20.0),
+            Vec2::new(97.0, 12.0),
+        ];
+        assert_eq!(first_rope_contact(pivot, from, to, corners), None);
+        assert_eq!(
+            first_rope_contact(
+                pivot,
+                from,
+                to,
+                [Vec2::new(60.0, 20.0), Vec2::new(50.0, 5.0)]
+            ),
+            Some(Vec2::new(50.0, 5.0))
         );
+    }
+
+    /// Player hanging from `anchor` with their center at `center`, above a distant floor
+    fn hanging_harness(
+        anchor: Vec2,
+        center: Vec2,
+        blocks: &[(f32, f32, f32, f32)],
+    ) -> SimulationHarness {
+        let mut blocks = blocks.to_vec();
+        blocks.push((-600.0, 600.0, 1200.0, 64.0));
+        let level = block_level(
+            "hanging",
+            Position::new(center.x - 16.0, center.y - 32.0),
+            &blocks,
+        );
+        let mut harness = SimulationHarness::new(level);
+        let player = harness.player();
+        harness
+            .world_mut()
+            .entity_mut(player)
+            .insert(SwingState::new(anchor, anchor.distance(center), 0.0));
+        harness
+    }
 
-        // Apply left input
-        angular_velocity -= SWING_INPUT_TORQUE * delta_time * 2.0;
+    fn hold_swing() -> PlayerIntent {
+        PlayerIntent {
+            swing_pressed: true,
+            ..Default::default()
+        }
+    }
 
+    #[test]
+    fn test_rope_wraps_around_peg_while_swinging() {
+        // Held out level, the player swings down into a peg below and right of the anchor
+        let mut harness =
+            hanging_harness(Vec2::ZERO, Vec2::new(150.0, 0.0), &[(40.0, 40.0, 8.0, 8.0)]);
+
+        let mut wrapped = None;
+        for _ in 0..60 {
+            harness.step(hold_swing());
+            let swing = harness.player_get::<SwingState>().unwrap();
+            if !swing.wraps.is_empty() {
+                wrapped = Some(swing.clone());
+                break;
+            }
+        }
+
+        // The rope first touches the peg's top-right corner, and the player keeps swinging
+        // around it on the rope that is left
+        let swing = wrapped.expect("Rope should wrap around the peg");
+        assert_eq!(swing.pivot(), Vec2::new(48.0, 40.0));
+        harness.step_n(5, hold_swing());
+        let swing = harness.player_get::<SwingState>().unwrap().clone();
+        assert_eq!(swing.wraps.len(), 1);
+        let center = player_center(&harness);
         assert!(
-            angular_velocity < 0.0,
-            "Left input should decrease angular velocity"
+            (center.distance(swing.pivot()) - swing.free_length()).abs() < 0.01,
+            "Player at {center} should be on the rope around {}",
+            swing.pivot()
         );
     }
 
     #[test]
-    fn test_tangential_velocity_calculation() {
-        let rope_length = 100.0;
-        let angular_velocity = 1.0; // radians per second
-        let angle: f32 = 0.0; // Horizontal position
+    fn test_swinging_player_collides_with_wall() {
+        // Swinging down from the left, the player would pass through a wall right of the anchor
+        let mut harness = hanging_harness(
+            Vec2::ZERO,
+            Vec2::new(-100.0, 0.0),
+            &[(40.0, 20.0, 32.0, 300.0)],
+        );
 
-        // Calculate tangential velocity
-        let velocity_x = -rope_length * angular_velocity * angle.sin();
-        let velocity_y = rope_length * angular_velocity * angle.cos();
+        for _ in 0..90 {
+            harness.step(hold_swing());
+            let right = harness.player_position().x + 32.0;
+            assert!(
+                right <= 40.01,
+                "Player swung into the wall, right edge at {right}"
+            );
+        }
 
-        // At angle 0, sin(0) = 0, cos(0) = 1
-        assert_eq!(
-            velocity_x, 0.0,
-            "Horizontal velocity should be 0 at angle 0"
-        );
-        assert_eq!(
-            velocity_y, 100.0,
-            "Vertical velocity should equal rope_length * angular_velocity"
-        );
+        // The wall took the swing, which then falls back the other way
+        assert!(harness.player_get::<SwingState>().is_some());
+        assert!(player_center(&harness).x < 24.0);
     }
 
     #[test]
-    fn test_swing_position_on_arc() {
-        let anchor = Vec2::new(100.0, 200.0);
-        let rope_length = 50.0;
-        let angle: f32 = 0.0; // Horizontal right
+    fn test_reel_rope_keeps_angular_momentum_within_limits() {
+        let mut swing = SwingState::new(Vec2::ZERO, 100.0, 1.0).with_length_limits(50.0, 150.0);
 
-        let position_x = anchor.x + rope_length * angle.cos();
-        let position_y = anchor.y + rope_length * angle.sin();
+        assert_eq!(reel_rope(&mut swing, -20.0), 80.0);
+        let momentum = swing.angular_velocity * 80.0 * 80.0;
+        assert!((momentum - 100.0 * 100.0).abs() < 0.01);
 
-        // At angle 0, cos(0) = 1, sin(0) = 0
-        assert_eq!(
-            position_x, 150.0,
-            "Position should be rope_length to the right"
-        );
-        assert_eq!(position_y, 200.0, "Position should be at same height");
+        assert_eq!(reel_rope(&mut swing, -100.0), 50.0);
+        assert_eq!(reel_rope(&mut swing, 500.0), 150.0);
+        let momentum = swing.angular_velocity * 150.0 * 150.0;
+        assert!((momentum - 100.0 * 100.0).abs() < 0.01);
     }
 
     #[test]
-    fn test_no_swing_when_grounded() {
-        let grounded = GroundedState {
-            is_grounded: true,
-            ground_normal: Vec2::new(0.0, -1.0),
-        };
+    fn test_reel_rope_stops_short_of_wrapped_corner() {
+        let mut swing = SwingState::new(Vec2::ZERO, 100.0, 1.0).with_length_limits(0.0, 100.0);
+        swing.wraps.push(RopeWrap {
+            corner: Vec2::new(0.0, 60.0),
+            side: 1.0,
+        });
+
+        assert_eq!(reel_rope(&mut swing, -100.0), MIN_FREE_ROPE);
+        assert_eq!(swing.rope_length, 60.0 + MIN_FREE_ROPE);
+    }
 
-        // Should not attach to swing when grounded
-        assert!(
-            grounded.is_grounded,
-            "Should not be able to swing when grounded"
-        );
+    /// Let the hanging player's rope be reeled between `min_length` and `max_length`
+    fn allow_reeling(harness: &mut SimulationHarness, min_length: f32, max_length: f32) {
+        let player = harness.player();
+        let mut swing = harness.world_mut().get_mut::<SwingState>(player).unwrap();
+        swing.min_length = min_length;
+        swing.max_length = max_length;
     }
 
     #[test]
-    fn test_swing_allowed_when_airborne() {
-        let grounded = GroundedState {
-            is_grounded: false,
-            ground_normal: Vec2::ZERO,
+    fn test_up_and_down_reel_within_limits() {
+        let mut harness = hanging_harness(Vec2::ZERO, Vec2::new(0.0, 100.0), &[]);
+        allow_reeling(&mut harness, 40.0, 140.0);
+
+        let reel_in = PlayerIntent {
+            move_up: true,
+            ..hold_swing()
         };
+        harness.step_n(10, reel_in);
+        let swing = harness.player_get::<SwingState>().unwrap();
+        assert!((swing.rope_length - 80.0).abs() < 0.01);
 
-        // Should be able to attach to swing when airborne
-        assert!(
-            !grounded.is_grounded,
-            "Should be able to swing when airborne"
+        harness.step_n(60, reel_in);
+        assert_eq!(
+            harness.player_get::<SwingState>().unwrap().rope_length,
+            40.0
         );
+        assert!((player_center(&harness).distance(Vec2::ZERO) - 40.0).abs() < 0.01);
+
+        let let_out = PlayerIntent {
+            move_down: true,
+            ..hold_swing()
+        };
+        harness.step_n(120, let_out);
+        assert_eq!(
+            harness.player_get::<SwingState>().unwrap().rope_length,
+            140.0
+        );
+        assert!((player_center(&harness).distance(Vec2::ZERO) - 140.0).abs() < 0.01);
+    }
+
+    /// Height above the anchor plus speed, per unit mass
+    fn swing_energy(harness: &SimulationHarness) -> f32 {
+        let velocity = harness.player_get::<Velocity>().unwrap();
+        let speed = Vec2::new(velocity.x, velocity.y).length();
+        0.5 * speed * speed - MovementTuning::default().gravity * player_center(harness).y
+    }
+
+    #[test]
+    fn test_pumping_rope_adds_energy() {
+        let start = Vec2::new(100.0, 0.0);
+        let mut passive = hanging_harness(Vec2::ZERO, start, &[]);
+        let mut pumped = hanging_harness(Vec2::ZERO, start, &[]);
+        allow_reeling(&mut passive, 40.0, 100.0);
+        allow_reeling(&mut pumped, 40.0, 100.0);
+
+        // Reel in through the bottom of each swing and let out again toward its ends
+        for _ in 0..90 {
+            passive.step(hold_swing());
+            let low = player_center(&pumped).x.abs() < 30.0;
+            pumped.step(PlayerIntent {
+                move_up: low,
+                move_down: !low,
+                ..hold_swing()
+            });
+        }
+
+        assert!(swing_energy(&pumped) > swing_energy(&passive));
     }
 }
@@ -0,0 +1,287 @@
+use crate::components::{Collider, CollisionLayers, Position, Velocity};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Marks a sensor area: its Position and Collider are tested for overlap but never block movement
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Trigger;
+
+/// Sent the first fixed tick a body overlaps a trigger
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TriggerEntered {
+    pub trigger: Entity,
+    pub entity: Entity,
+}
+
+/// Sent the first fixed tick a body stops overlapping a trigger
+/// Not sent when either entity is despawned while overlapping
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TriggerExited {
+    pub trigger: Entity,
+    pub entity: Entity,
+}
+
+/// Whether two colliders overlap with positive area, so touching edges do not count
+pub fn colliders_overlap(
+    position: &Position,
+    collider: &Collider,
+    other_position: &Position,
+    other_collider: &Collider,
+) -> bool {
+    let left = position.x + collider.offset_x;
+    let top = position.y + collider.offset_y;
+    let other_left = other_position.x + other_collider.offset_x;
+    let other_top = other_position.y + other_collider.offset_y;
+
+    left < other_left + other_collider.width
+        && other_left < left + collider.width
+        && top < other_top + other_collider.height
+        && other_top < top + collider.height
+}
+
+/// Compare every moving body against every trigger and report overlaps that began or ended
+/// Runs every fixed tick after bodies move, so a body passing through a trigger within one
+/// frame is still seen, and gameplay systems read the events the same frame
+#[allow(clippy::type_complexity)]
+pub(crate) fn detect_triggers(
+    mut overlapping: Local<HashSet<(Entity, Entity)>>,
+    trigger_query: Query<(Entity, &Position, &Collider, Option<&CollisionLayers>), With<Trigger>>,
+    body_query: Query<
+        (Entity, &Position, &Collider, Option<&CollisionLayers>),
+        (With<Velocity>, Without<Trigger>),
+    >,
+    mut entered_events: EventWriter<TriggerEntered>,
+    mut exited_events: EventWriter<TriggerExited>,
+) {
+    let mut current = HashSet::new();
+
+    for (trigger, trigger_pos, trigger_collider, trigger_layers) in trigger_query.iter() {
+        for (entity, position, collider, layers) in body_query.iter() {
+            // Triggers without layers accept every body
+            if let Some(trigger_layers) = trigger_layers
+                && !trigger_layers.interacts_with(&layers.copied().unwrap_or_default())
+            {
+                continue;
+            }
+
+            if colliders_overlap(position, collider, trigger_pos, trigger_collider) {
+                current.insert((trigger, entity));
+            }
+        }
+    }
+
+    // Sort so events arrive in the same order every run
+    let mut entered: Vec<_> = current.difference(&overlapping).copied().collect();
+    entered.sort_unstable();
+    for (trigger, entity) in entered {
+        entered_events.send(TriggerEntered { trigger, entity });
+    }
+
+    let mut exited: Vec<_> = overlapping
+        .difference(&current)
+        .copied()
+        .filter(|&(trigger, entity)| trigger_query.contains(trigger) && body_query.contains(entity))
+        .collect();
+    exited.sort_unstable();
+    for (trigger, entity) in exited {
+        exited_events.send(TriggerExited { trigger, entity });
+    }
+
+    *overlapping = current;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::PhysicsPlugin;
+    use crate::test_util::run_fixed_step;
+    use bevy::time::TimeUpdateStrategy;
+
+    fn trigger_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(PhysicsPlugin);
+        app
+    }
+
+    /// Body that moves and so can set off triggers
+    fn body(x: f32, y: f32, size: f32) -> (Position, Velocity, Collider) {
+        (
+            Position::new(x, y),
+            Velocity::default(),
+            Collider::new(size, size),
+        )
+    }
+
+    /// Events sent since the last drain
+    fn entered(app: &mut App) -> Vec<TriggerEntered> {
+        app.world
+            .resource_mut::<Events<TriggerEntered>>()
+            .drain()
+            .collect()
+    }
+
+    fn exited(app: &mut App) -> Vec<TriggerExited> {
+        app.world
+            .resource_mut::<Events<TriggerExited>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn test_colliders_overlap_excludes_touching_edges() {
+        let collider = Collider::new(32.0, 32.0);
+        let origin = Position::new(0.0, 0.0);
+
+        assert!(colliders_overlap(
+            &origin,
+            &collider,
+            &Position::new(31.0, 31.0),
+            &collider
+        ));
+        assert!(!colliders_overlap(
+            &origin,
+            &collider,
+            &Position::new(32.0, 0.0),
+            &collider
+        ));
+    }
+
+    #[test]
+    fn test_centered_collider_overlaps_around_position() {
+        let trigger = Collider::centered(32.0, 32.0);
+        let body = Collider::new(10.0, 10.0);
+
+        assert!(colliders_overlap(
+            &Position::new(-20.0, -20.0),
+            &body,
+            &Position::new(0.0, 0.0),
+            &trigger
+        ));
+        assert!(!colliders_overlap(
+            &Position::new(16.0, 0.0),
+            &body,
+            &Position::new(0.0, 0.0),
+            &trigger
+        ));
+    }
+
+    #[test]
+    fn test_trigger_entered_once_and_exited() {
+        let mut app = trigger_app();
+        let trigger = app
+            .world
+            .spawn((Trigger, Position::new(0.0, 0.0), Collider::new(64.0, 64.0)))
+            .id();
+        let body = app.world.spawn(body(16.0, 16.0, 32.0)).id();
+
+        run_fixed_step(&mut app);
+        assert_eq!(
+            entered(&mut app),
+            vec![TriggerEntered {
+                trigger,
+                entity: body
+            }]
+        );
+
+        // Staying inside sends nothing new
+        run_fixed_step(&mut app);
+        assert!(entered(&mut app).is_empty());
+
+        app.world.get_mut::<Position>(body).unwrap().x = 200.0;
+        run_fixed_step(&mut app);
+        assert_eq!(
+            exited(&mut app),
+            vec![TriggerExited {
+                trigger,
+                entity: body
+            }]
+        );
+    }
+
+    #[test]
+    fn test_trigger_respects_layers() {
+        let mut app = trigger_app();
+        app.world.spawn((
+            Trigger,
+            Position::new(0.0, 0.0),
+            Collider::new(64.0, 64.0),
+            CollisionLayers::new(CollisionLayers::DEFAULT, CollisionLayers::PLAYER),
+        ));
+        let player = app
+            .world
+            .spawn((body(0.0, 0.0, 32.0), CollisionLayers::player()))
+            .id();
+        app.world.spawn((
+            body(0.0, 0.0, 32.0),
+            CollisionLayers::new(CollisionLayers::ENEMY, CollisionLayers::ALL),
+        ));
+
+        run_fixed_step(&mut app);
+
+        let events = entered(&mut app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, player);
+    }
+
+    #[test]
+    fn test_despawned_trigger_sends_no_exit() {
+        let mut app = trigger_app();
+        let trigger = app
+            .world
+            .spawn((Trigger, Position::new(0.0, 0.0), Collider::new(64.0, 64.0)))
+            .id();
+        app.world.spawn(body(0.0, 0.0, 32.0));
+
+        run_fixed_step(&mut app);
+        app.world.despawn(trigger);
+        run_fixed_step(&mut app);
+
+        assert!(exited(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_static_entities_are_not_bodies() {
+        let mut app = trigger_app();
+        app.world
+            .spawn((Trigger, Position::new(0.0, 0.0), Collider::new(64.0, 64.0)));
+        app.world
+            .spawn((Position::new(0.0, 0.0), Collider::new(32.0, 32.0)));
+
+        run_fixed_step(&mut app);
+
+        assert!(entered(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_body_crossing_trigger_within_one_frame() {
+        let mut app = trigger_app();
+        let timestep = app.world.resource::<Time<Fixed>>().timestep();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep * 3));
+        let trigger = app
+            .world
+            .spawn((Trigger, Position::new(20.0, 0.0), Collider::new(10.0, 8.0)))
+            .id();
+
+        // 10 pixels a tick: clear of the trigger, inside it, then past it
+        let (position, mut velocity, collider) = body(0.0, 0.0, 8.0);
+        velocity.x = 600.0;
+        let player = app.world.spawn((position, velocity, collider)).id();
+
+        // The clock starts on the first frame, then one frame runs all three ticks
+        app.update();
+        app.update();
+
+        assert!((app.world.get::<Position>(player).unwrap().x - 30.0).abs() < 0.01);
+        let expected = (
+            vec![TriggerEntered {
+                trigger,
+                entity: player,
+            }],
+            vec![TriggerExited {
+                trigger,
+                entity: player,
+            }],
+        );
+        assert_eq!((entered(&mut app), exited(&mut app)), expected);
+    }
+}
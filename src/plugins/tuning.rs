@@ -0,0 +1,183 @@
+use crate::tuning::MovementTuning;
+use bevy::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Resource naming the tuning file to watch
+#[derive(Resource, Clone, Debug)]
+pub struct TuningFilePath {
+    pub path: PathBuf,
+}
+
+impl Default for TuningFilePath {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("tuning.ron"),
+        }
+    }
+}
+
+/// Plugin that loads MovementTuning from disk and reloads it whenever the file changes
+pub struct TuningPlugin;
+
+impl Plugin for TuningPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MovementTuning>()
+            .init_resource::<TuningFilePath>()
+            .add_systems(PreUpdate, reload_tuning_system);
+    }
+}
+
+/// Load movement tuning from a `.ron` file, or JSON for any other extension
+pub fn load_tuning_from_file(path: &Path) -> Result<MovementTuning, TuningLoadError> {
+    let display = path.display().to_string();
+    if !path.exists() {
+        return Err(TuningLoadError::FileNotFound(display));
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|e| TuningLoadError::IoError(display.clone(), e.to_string()))?;
+
+    let is_ron = path.extension().is_some_and(|extension| extension == "ron");
+    if is_ron {
+        ron::from_str(&contents).map_err(|e| TuningLoadError::ParseError(display, e.to_string()))
+    } else {
+        serde_json::from_str(&contents)
+            .map_err(|e| TuningLoadError::ParseError(display, e.to_string()))
+    }
+}
+
+/// Reload tuning when the file's modification time changes
+/// A missing or invalid file keeps the current tuning, so a half-saved edit never resets feel
+fn reload_tuning_system(
+    file: Res<TuningFilePath>,
+    mut tuning: ResMut<MovementTuning>,
+    mut last_modified: Local<Option<SystemTime>>,
+) {
+    let Ok(modified) = fs::metadata(&file.path).and_then(|metadata| metadata.modified()) else {
+        return;
+    };
+    if *last_modified == Some(modified) {
+        return;
+    }
+    *last_modified = Some(modified);
+
+    match load_tuning_from_file(&file.path) {
+        Ok(loaded) => {
+            info!("Loaded movement tuning from {}", file.path.display());
+            *tuning = loaded;
+        }
+        Err(e) => {
+            error!("{}", e);
+        }
+    }
+}
+
+/// Tuning loading errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum TuningLoadError {
+    FileNotFound(String),
+    IoError(String, String),
+    ParseError(String, String),
+}
+
+impl std::fmt::Display for TuningLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TuningLoadError::FileNotFound(path) => write!(f, "Tuning file not found: {}", path),
+            TuningLoadError::IoError(path, err) => {
+                write!(f, "IO error reading tuning file {}: {}", path, err)
+            }
+            TuningLoadError::ParseError(path, err) => {
+                write!(f, "Failed to parse tuning file {}: {}", path, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TuningLoadError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn tuning_app(path: &Path) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(TuningPlugin)
+            .insert_resource(TuningFilePath {
+                path: path.to_path_buf(),
+            });
+        app
+    }
+
+    #[test]
+    fn test_load_tuning_from_json_and_ron() {
+        let mut json = NamedTempFile::with_suffix(".json").unwrap();
+        write!(json, r#"{{ "move_speed": 260.0 }}"#).unwrap();
+        assert_eq!(
+            load_tuning_from_file(json.path()).unwrap().move_speed,
+            260.0
+        );
+
+        let mut ron = NamedTempFile::with_suffix(".ron").unwrap();
+        write!(ron, "(gravity: 1500.0)").unwrap();
+        assert_eq!(load_tuning_from_file(ron.path()).unwrap().gravity, 1500.0);
+    }
+
+    #[test]
+    fn test_load_tuning_errors() {
+        assert!(matches!(
+            load_tuning_from_file(Path::new("nonexistent_tuning.ron")),
+            Err(TuningLoadError::FileNotFound(_))
+        ));
+
+        let mut invalid = NamedTempFile::with_suffix(".ron").unwrap();
+        write!(invalid, "(gravity: ").unwrap();
+        assert!(matches!(
+            load_tuning_from_file(invalid.path()),
+            Err(TuningLoadError::ParseError(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_tuning_hot_reloads_when_file_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tuning.ron");
+        fs::write(&path, "(move_speed: 220.0)").unwrap();
+
+        let mut app = tuning_app(&path);
+        app.update();
+        assert_eq!(app.world.resource::<MovementTuning>().move_speed, 220.0);
+
+        fs::write(&path, "(move_speed: 300.0)").unwrap();
+        // Make sure the edit is seen even on filesystems with coarse timestamps
+        let later = SystemTime::now() + std::time::Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        app.update();
+        assert_eq!(app.world.resource::<MovementTuning>().move_speed, 300.0);
+    }
+
+    #[test]
+    fn test_invalid_edit_keeps_current_tuning() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tuning.json");
+        fs::write(&path, "{ \"gravity\": ").unwrap();
+
+        let mut app = tuning_app(&path);
+        app.update();
+
+        assert_eq!(
+            *app.world.resource::<MovementTuning>(),
+            MovementTuning::default()
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::components::{Collider, ForceVolume, Position};
+use crate::enums::VolumeEffect;
+use bevy::prelude::*;
+
+/// Combined effect of every force volume a body overlaps
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VolumeEffects {
+    pub gravity: Vec2, // Multiple of MovementTuning::gravity, straight down outside volumes
+    pub acceleration: Vec2, // Wind and buoyancy, applied on top of gravity
+    pub drag: f32,     // Fraction of velocity lost per second
+    pub swim_speed: Option<f32>, // Set while in water
+}
+
+impl Default for VolumeEffects {
+    fn default() -> Self {
+        Self {
+            gravity: Vec2::new(0.0, 1.0),
+            acceleration: Vec2::ZERO,
+            drag: 0.0,
+            swim_speed: None,
+        }
+    }
+}
+
+impl VolumeEffects {
+    /// Whether the body is in water
+    pub fn in_water(&self) -> bool {
+        self.swim_speed.is_some()
+    }
+}
+
+/// Whether a body's collider overlaps a force volume
+pub fn overlaps_volume(position: &Position, collider: &Collider, volume: &ForceVolume) -> bool {
+    let left = position.x + collider.offset_x;
+    let top = position.y + collider.offset_y;
+    left < volume.x + volume.width
+        && left + collider.width > volume.x
+        && top < volume.y + volume.height
+        && top + collider.height > volume.y
+}
+
+/// Sum the effects of every volume the body overlaps
+/// Wind, buoyancy and drag add up, gravity volumes together replace normal gravity, and
+/// overlapping water keeps the slowest swim speed
+pub fn volume_effects_at<'a, I>(
+    position: &Position,
+    collider: &Collider,
+    volumes: I,
+) -> VolumeEffects
+where
+    I: IntoIterator<Item = &'a ForceVolume>,
+{
+    let mut effects = VolumeEffects::default();
+    let mut gravity: Option<Vec2> = None;
+
+    for volume in volumes {
+        if !overlaps_volume(position, collider, volume) {
+            continue;
+        }
+
+        match volume.effect {
+            VolumeEffect::Wind { x, y } => effects.acceleration += Vec2::new(x, y),
+            VolumeEffect::Water {
+                buoyancy,
+                drag,
+                swim_speed,
+            } => {
+                effects.acceleration.y -= buoyancy;
+                effects.drag += drag;
+                effects.swim_speed = Some(
+                    effects
+                        .swim_speed
+                        .map_or(swim_speed, |speed| speed.min(swim_speed)),
+                );
+            }
+            VolumeEffect::Gravity { x, y } => {
+                *gravity.get_or_insert(Vec2::ZERO) += Vec2::new(x, y);
+            }
+        }
+    }
+
+    if let Some(gravity) = gravity {
+        effects.gravity = gravity;
+    }
+    effects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wind(x: f32, y: f32) -> ForceVolume {
+        ForceVolume::new(0.0, 0.0, 100.0, 100.0, VolumeEffect::Wind { x, y })
+    }
+
+    #[test]
+    fn test_no_volumes_is_normal_gravity() {
+        let effects = volume_effects_at(
+            &Position::new(50.0, 50.0),
+            &Collider::new(32.0, 32.0),
+            std::iter::empty(),
+        );
+        assert_eq!(effects, VolumeEffects::default());
+        assert!(!effects.in_water());
+    }
+
+    #[test]
+    fn test_only_overlapping_volumes_apply() {
+        let volumes = [wind(100.0, 0.0)];
+        let collider = Collider::new(32.0, 32.0);
+
+        let inside = volume_effects_at(&Position::new(90.0, 10.0), &collider, &volumes);
+        assert_eq!(inside.acceleration, Vec2::new(100.0, 0.0));
+
+        // Touching the edge is not inside
+        let outside = volume_effects_at(&Position::new(100.0, 10.0), &collider, &volumes);
+        assert_eq!(outside.acceleration, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_overlapping_volumes_sum() {
+        let volumes = [
+            wind(100.0, 0.0),
+            wind(-30.0, -20.0),
+            ForceVolume::new(
+                0.0,
+                0.0,
+                100.0,
+                100.0,
+                VolumeEffect::Water {
+                    buoyancy: 500.0,
+                    drag: 2.0,
+                    swim_speed: 150.0,
+                },
+            ),
+            ForceVolume::new(
+                0.0,
+                0.0,
+                100.0,
+                100.0,
+                VolumeEffect::Water {
+                    buoyancy: 100.0,
+                    drag: 1.0,
+                    swim_speed: 90.0,
+                },
+            ),
+            ForceVolume::new(
+                0.0,
+                0.0,
+                100.0,
+                100.0,
+                VolumeEffect::Gravity { x: 0.0, y: 0.25 },
+            ),
+            ForceVolume::new(
+                0.0,
+                0.0,
+                100.0,
+                100.0,
+                VolumeEffect::Gravity { x: 0.0, y: 0.25 },
+            ),
+        ];
+
+        let effects = volume_effects_at(
+            &Position::new(10.0, 10.0),
+            &Collider::new(32.0, 32.0),
+            &volumes,
+        );
+
+        assert_eq!(effects.acceleration, Vec2::new(70.0, -620.0));
+        assert_eq!(effects.drag, 3.0);
+        assert_eq!(effects.swim_speed, Some(90.0));
+        assert_eq!(effects.gravity, Vec2::new(0.0, 0.5));
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+/// Advance the fixed clock by one timestep and run FixedUpdate once, without a frame
+pub(crate) fn run_fixed_step(app: &mut App) {
+    let timestep = app.world.resource::<Time<Fixed>>().timestep();
+    app.world.resource_mut::<Time<Fixed>>().advance_by(timestep);
+    app.world.run_schedule(FixedUpdate);
+}
+
+/// Run one fixed tick, then the frame that reads the events it sent
+pub(crate) fn run_tick(app: &mut App) {
+    run_fixed_step(app);
+    app.update();
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::{Component, Resource};
+use serde::{Deserialize, Serialize};
+
+/// Movement feel shared by the physics, player and swing plugins
+/// Loaded from a JSON or RON file; fields missing from the file keep their defaults
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct MovementTuning {
+    pub gravity: f32,        // pixels per second squared
+    pub max_fall_speed: f32, // Terminal velocity in pixels per second
+    pub slope_friction: f32, // Friction coefficient for slopes
+
+    pub move_speed: f32,            // Top running speed in pixels per second
+    pub ground_acceleration: f32,   // pixels per second squared toward the input's target speed
+    pub ground_deceleration: f32,   // pixels per second squared when stopping or above target speed
+    pub air_acceleration: f32,      // As ground_acceleration, while airborne
+    pub air_deceleration: f32,      // As ground_deceleration, while airborne
+    pub turnaround_multiplier: f32, // Acceleration multiplier while reversing direction
+    pub swim_acceleration: f32,     // pixels per second squared toward the swim speed in water
+    pub base_jump_velocity: f32,    // pixels per second (negative = up)
+    pub high_jump_velocity: f32,    // pixels per second (negative = up)
+    pub jump_release_damping: f32,  // Fraction of upward velocity kept when jump is released early
+    pub coyote_ticks: u32,          // Fixed ticks after leaving the ground that a jump still works
+    pub jump_buffer_ticks: u32,     // Fixed ticks a jump pressed too early waits for the ground
+    pub wall_climb_speed: f32,      // pixels per second
+    pub wall_jump_horizontal_velocity: f32, // pixels per second
+    pub wall_jump_vertical_velocity: f32, // pixels per second (negative = up)
+
+    pub swing_damping: f32,      // Angular velocity damping per fixed tick
+    pub swing_input_torque: f32, // Torque applied by player input
+    pub swing_reel_speed: f32,   // Rope reeled in or out per second, in pixels
+}
+
+impl Default for MovementTuning {
+    fn default() -> Self {
+        Self {
+            gravity: 980.0,
+            max_fall_speed: 1200.0,
+            slope_friction: 0.3,
+
+            move_speed: 200.0,
+            ground_acceleration: 2400.0,
+            ground_deceleration: 3000.0,
+            air_acceleration: 1200.0,
+            air_deceleration: 400.0,
+            turnaround_multiplier: 2.0,
+            swim_acceleration: 900.0,
+            base_jump_velocity: -400.0,
+            high_jump_velocity: -600.0,
+            jump_release_damping: 0.5,
+            coyote_ticks: 6,
+            jump_buffer_ticks: 6,
+            wall_climb_speed: 150.0,
+            wall_jump_horizontal_velocity: 250.0,
+            wall_jump_vertical_velocity: -450.0,
+
+            swing_damping: 0.98,
+            swing_input_torque: 2.0,
+            swing_reel_speed: 120.0,
+        }
+    }
+}
+
+/// Movement feel for one character, used by the player systems instead of the global tuning
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CharacterTuning(pub MovementTuning);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_json_keeps_defaults() {
+        let tuning: MovementTuning =
+            serde_json::from_str(r#"{ "gravity": 1200.0, "move_speed": 250.0 }"#).unwrap();
+
+        assert_eq!(tuning.gravity, 1200.0);
+        assert_eq!(tuning.move_speed, 250.0);
+        assert_eq!(
+            tuning.base_jump_velocity,
+            MovementTuning::default().base_jump_velocity
+        );
+    }
+
+    #[test]
+    fn test_ron_round_trip() {
+        let tuning = MovementTuning {
+            swing_damping: 0.95,
+            ..Default::default()
+        };
+
+        let text = ron::to_string(&tuning).unwrap();
+        let parsed: MovementTuning = ron::from_str(&text).unwrap();
+        assert_eq!(parsed, tuning);
+    }
+}